@@ -89,6 +89,38 @@ macro_rules! serde {
     ($($tt:tt)*) => {};
 }
 
+/// Expands the given block of code when `uom` is compiled with the `schemars` feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "schemars")]
+macro_rules! schemars {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+/// Does not expand the given block of code when `uom` is compiled without the `schemars` feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "schemars"))]
+macro_rules! schemars {
+    ($($tt:tt)*) => {};
+}
+
+/// Expands the given block of code when `uom` is compiled with the `wire` feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "wire")]
+macro_rules! wire {
+    ($($tt:tt)*) => { $($tt)* };
+}
+
+/// Does not expand the given block of code when `uom` is compiled without the `wire` feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "wire"))]
+macro_rules! wire {
+    ($($tt:tt)*) => {};
+}
+
 /// Expands the given block of code when `uom` is compiled with the `si` feature.
 #[doc(hidden)]
 #[macro_export]
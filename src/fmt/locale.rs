@@ -0,0 +1,545 @@
+//! Localized unit names and number formatting.
+//!
+//! A [`Locale`] provides [CLDR][cldr] plural rules, decimal and grouping separators, and
+//! translated quantity descriptions and unit names. Quantities are displayed using a locale with
+//! `into_localized_format_args`. Units without a translation fall back to the English singular and
+//! plural descriptions given in `quantity!` and `unit!`.
+//!
+//! Built-in catalogs are provided for [English](ENGLISH), [German](GERMAN), [French](FRENCH), and
+//! [Japanese](JAPANESE). Downstream crates can provide names for their own units by defining a
+//! [`Catalog`] that falls back to a built-in catalog, or by implementing [`Locale`].
+//!
+#![cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+//! # use uom::si::f32::*;
+//! # use uom::si::length::Units;
+//! use uom::fmt::locale::{Catalog, PluralCategory, UnitName, GERMAN};
+//!
+//! const SWISS_GERMAN: Catalog = Catalog {
+//!     grouping_separator: Some('\''),
+//!     units: &[UnitName {
+//!         quantity: "length",
+//!         unit: "foot",
+//!         names: &[(PluralCategory::One, "Fuss"), (PluralCategory::Other, "Fuss")],
+//!     }],
+//!     fallback: Some(&GERMAN),
+//!     ..GERMAN
+//! };
+//!
+//! let l = Length::new::<uom::si::length::foot>(1_500.0);
+//!
+//! assert_eq!("1'500 Fuss",
+//!     format!("{}", l.into_localized_format_args(Units::foot, &SWISS_GERMAN)));
+//! assert_eq!("Länge", uom::si::length::localized_description(&SWISS_GERMAN));
+//! ```
+//!
+//! [cldr]: https://cldr.unicode.org/index/cldr-spec/plural-rules
+
+use crate::lib::fmt::{self, Write};
+
+/// Names of a unit with one and other plural forms.
+macro_rules! unit_name {
+    ($quantity:expr, $unit:expr, $one:expr, $other:expr) => {
+        UnitName {
+            quantity: $quantity,
+            unit: $unit,
+            names: &[(PluralCategory::One, $one), (PluralCategory::Other, $other)],
+        }
+    };
+}
+
+/// [CLDR plural category][cldr] of a number.
+///
+/// [cldr]: https://cldr.unicode.org/index/cldr-spec/plural-rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// Category used for zero in some languages, e.g. Arabic.
+    Zero,
+
+    /// Category used for one and similar numbers, e.g. "1 meter".
+    One,
+
+    /// Category used for two in some languages, e.g. Welsh.
+    Two,
+
+    /// Category used for a few numbers in some languages, e.g. 2-4 in Polish.
+    Few,
+
+    /// Category used for many numbers in some languages, e.g. 5-21 in Polish.
+    Many,
+
+    /// Category used for all other numbers, e.g. "2 meters", "1.5 meters".
+    Other,
+}
+
+/// [CLDR plural operands][cldr] of a displayed number. Operands are determined from the number as
+/// displayed, e.g. "1.50" has `i = 1`, `v = 2`, `f = 50`, and `t = 5`. Integer and fraction
+/// digits beyond the range of `u64` keep their last 18 digits and are offset by 10¹⁸.
+///
+/// [cldr]: https://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Operands {
+    /// Integer digits of the absolute value.
+    pub i: u64,
+
+    /// Number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+
+    /// Visible fraction digits, with trailing zeros.
+    pub f: u64,
+
+    /// Visible fraction digits, without trailing zeros.
+    pub t: u64,
+}
+
+/// Localized number formatting, plural rules, and names.
+pub trait Locale {
+    /// Decimal separator, e.g. `','` for "1,5".
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+
+    /// Separator between groups of three integer digits, e.g. `'.'` for "1.500". `None` when
+    /// digits are not grouped.
+    fn grouping_separator(&self) -> Option<char> {
+        None
+    }
+
+    /// Separator between the value and the unit name, e.g. `" "`.
+    fn unit_separator(&self) -> &str {
+        " "
+    }
+
+    /// Plural category of a number with the given operands.
+    fn plural_category(&self, operands: &Operands) -> PluralCategory;
+
+    /// Localized description of the quantity with the given English description, e.g. "length".
+    fn quantity_description(&self, quantity: &str) -> Option<&str> {
+        let _ = quantity;
+
+        None
+    }
+
+    /// Localized name of the unit with the given English singular description, e.g. "meter", of
+    /// the quantity with the given English description.
+    fn unit_name(&self, quantity: &str, unit: &str, category: PluralCategory) -> Option<&str> {
+        let _ = (quantity, unit, category);
+
+        None
+    }
+}
+
+/// Localized names of a unit.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitName {
+    /// English description of the quantity, e.g. "length".
+    pub quantity: &'static str,
+
+    /// English singular description of the unit, e.g. "meter".
+    pub unit: &'static str,
+
+    /// Names of the unit by plural category. The [`PluralCategory::Other`] name is used for
+    /// categories without a name.
+    pub names: &'static [(PluralCategory, &'static str)],
+}
+
+/// Static catalog of localized names implementing [`Locale`].
+#[derive(Clone, Copy, Debug)]
+pub struct Catalog {
+    /// Decimal separator.
+    pub decimal_separator: char,
+
+    /// Separator between groups of three integer digits.
+    pub grouping_separator: Option<char>,
+
+    /// Separator between the value and the unit name.
+    pub unit_separator: &'static str,
+
+    /// Plural rule.
+    pub plural_category: fn(&Operands) -> PluralCategory,
+
+    /// Localized quantity descriptions keyed by English description.
+    pub quantities: &'static [(&'static str, &'static str)],
+
+    /// Localized unit names.
+    pub units: &'static [UnitName],
+
+    /// Catalog to search for quantities and units not found in this catalog.
+    pub fallback: Option<&'static Catalog>,
+}
+
+impl Locale for Catalog {
+    fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    fn grouping_separator(&self) -> Option<char> {
+        self.grouping_separator
+    }
+
+    fn unit_separator(&self) -> &str {
+        self.unit_separator
+    }
+
+    fn plural_category(&self, operands: &Operands) -> PluralCategory {
+        (self.plural_category)(operands)
+    }
+
+    fn quantity_description(&self, quantity: &str) -> Option<&str> {
+        self.quantities
+            .iter()
+            .find(|&&(q, _)| q == quantity)
+            .map(|&(_, description)| description)
+            .or_else(|| self.fallback?.quantity_description(quantity))
+    }
+
+    fn unit_name(&self, quantity: &str, unit: &str, category: PluralCategory) -> Option<&str> {
+        self.units
+            .iter()
+            .find(|name| name.quantity == quantity && name.unit == unit)
+            .and_then(|name| {
+                let find = |category| name.names.iter().find(|&&(c, _)| c == category);
+
+                find(category).or_else(|| find(PluralCategory::Other)).map(|&(_, name)| name)
+            })
+            .or_else(|| self.fallback?.unit_name(quantity, unit, category))
+    }
+}
+
+/// English plural rule: one for an integer value of 1, otherwise other.
+fn english(operands: &Operands) -> PluralCategory {
+    if operands.i == 1 && operands.v == 0 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// French plural rule: one for values less than 2, many for integer multiples of one million.
+fn french(operands: &Operands) -> PluralCategory {
+    if operands.i <= 1 {
+        PluralCategory::One
+    } else if operands.v == 0 && operands.i % 1_000_000 == 0 {
+        PluralCategory::Many
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// Japanese plural rule: always other.
+fn japanese(_operands: &Operands) -> PluralCategory {
+    PluralCategory::Other
+}
+
+/// English catalog, e.g. "1,500.5 meters". Unit names are the English descriptions of the units.
+pub const ENGLISH: Catalog = Catalog {
+    decimal_separator: '.',
+    grouping_separator: Some(','),
+    unit_separator: " ",
+    plural_category: english,
+    quantities: &[],
+    units: &[],
+    fallback: None,
+};
+
+/// German catalog, e.g. "1.500,5 Meter".
+pub const GERMAN: Catalog = Catalog {
+    decimal_separator: ',',
+    grouping_separator: Some('.'),
+    unit_separator: " ",
+    plural_category: english,
+    quantities: &[
+        ("length", "Länge"),
+        ("mass", "Masse"),
+        ("time", "Zeit"),
+        ("electric current", "elektrische Stromstärke"),
+        ("thermodynamic temperature", "thermodynamische Temperatur"),
+        ("amount of substance", "Stoffmenge"),
+        ("luminous intensity", "Lichtstärke"),
+        ("velocity", "Geschwindigkeit"),
+        ("volume", "Volumen"),
+        ("force", "Kraft"),
+        ("energy", "Energie"),
+        ("power", "Leistung"),
+        ("pressure", "Druck"),
+        ("frequency", "Frequenz"),
+    ],
+    units: &[
+        unit_name!("length", "kilometer", "Kilometer", "Kilometer"),
+        unit_name!("length", "meter", "Meter", "Meter"),
+        unit_name!("length", "centimeter", "Zentimeter", "Zentimeter"),
+        unit_name!("length", "millimeter", "Millimeter", "Millimeter"),
+        unit_name!("mass", "kilogram", "Kilogramm", "Kilogramm"),
+        unit_name!("mass", "gram", "Gramm", "Gramm"),
+        unit_name!("time", "day", "Tag", "Tage"),
+        unit_name!("time", "hour", "Stunde", "Stunden"),
+        unit_name!("time", "minute", "Minute", "Minuten"),
+        unit_name!("time", "second", "Sekunde", "Sekunden"),
+        unit_name!("electric current", "ampere", "Ampere", "Ampere"),
+        unit_name!("thermodynamic temperature", "kelvin", "Kelvin", "Kelvin"),
+        unit_name!("thermodynamic temperature", "degree Celsius", "Grad Celsius", "Grad Celsius"),
+        unit_name!("amount of substance", "mole", "Mol", "Mol"),
+        unit_name!("luminous intensity", "candela", "Candela", "Candela"),
+        unit_name!("velocity", "meter per second", "Meter pro Sekunde", "Meter pro Sekunde"),
+        unit_name!(
+            "velocity",
+            "kilometer per hour",
+            "Kilometer pro Stunde",
+            "Kilometer pro Stunde"
+        ),
+        unit_name!("volume", "liter", "Liter", "Liter"),
+        unit_name!("force", "newton", "Newton", "Newton"),
+        unit_name!("energy", "joule", "Joule", "Joule"),
+        unit_name!("power", "watt", "Watt", "Watt"),
+        unit_name!("pressure", "pascal", "Pascal", "Pascal"),
+        unit_name!("frequency", "hertz", "Hertz", "Hertz"),
+    ],
+    fallback: None,
+};
+
+/// French catalog, e.g. "1 500,5 mètres". Digits are grouped with a narrow no-break space and the
+/// unit name is separated by a no-break space.
+pub const FRENCH: Catalog = Catalog {
+    decimal_separator: ',',
+    grouping_separator: Some('\u{202f}'),
+    unit_separator: "\u{a0}",
+    plural_category: french,
+    quantities: &[
+        ("length", "longueur"),
+        ("mass", "masse"),
+        ("time", "temps"),
+        ("electric current", "courant électrique"),
+        ("thermodynamic temperature", "température thermodynamique"),
+        ("amount of substance", "quantité de matière"),
+        ("luminous intensity", "intensité lumineuse"),
+        ("velocity", "vitesse"),
+        ("volume", "volume"),
+        ("force", "force"),
+        ("energy", "énergie"),
+        ("power", "puissance"),
+        ("pressure", "pression"),
+        ("frequency", "fréquence"),
+    ],
+    units: &[
+        unit_name!("length", "kilometer", "kilomètre", "kilomètres"),
+        unit_name!("length", "meter", "mètre", "mètres"),
+        unit_name!("length", "centimeter", "centimètre", "centimètres"),
+        unit_name!("length", "millimeter", "millimètre", "millimètres"),
+        unit_name!("mass", "kilogram", "kilogramme", "kilogrammes"),
+        unit_name!("mass", "gram", "gramme", "grammes"),
+        unit_name!("time", "day", "jour", "jours"),
+        unit_name!("time", "hour", "heure", "heures"),
+        unit_name!("time", "minute", "minute", "minutes"),
+        unit_name!("time", "second", "seconde", "secondes"),
+        unit_name!("electric current", "ampere", "ampère", "ampères"),
+        unit_name!("thermodynamic temperature", "kelvin", "kelvin", "kelvins"),
+        unit_name!(
+            "thermodynamic temperature",
+            "degree Celsius",
+            "degré Celsius",
+            "degrés Celsius"
+        ),
+        unit_name!("amount of substance", "mole", "mole", "moles"),
+        unit_name!("luminous intensity", "candela", "candela", "candelas"),
+        unit_name!("velocity", "meter per second", "mètre par seconde", "mètres par seconde"),
+        unit_name!("velocity", "kilometer per hour", "kilomètre par heure", "kilomètres par heure"),
+        unit_name!("volume", "liter", "litre", "litres"),
+        unit_name!("force", "newton", "newton", "newtons"),
+        unit_name!("energy", "joule", "joule", "joules"),
+        unit_name!("power", "watt", "watt", "watts"),
+        unit_name!("pressure", "pascal", "pascal", "pascals"),
+        unit_name!("frequency", "hertz", "hertz", "hertz"),
+    ],
+    fallback: None,
+};
+
+/// Japanese catalog, e.g. "1,500.5メートル".
+pub const JAPANESE: Catalog = Catalog {
+    decimal_separator: '.',
+    grouping_separator: Some(','),
+    unit_separator: "",
+    plural_category: japanese,
+    quantities: &[
+        ("length", "長さ"),
+        ("mass", "質量"),
+        ("time", "時間"),
+        ("electric current", "電流"),
+        ("thermodynamic temperature", "熱力学温度"),
+        ("amount of substance", "物質量"),
+        ("luminous intensity", "光度"),
+        ("velocity", "速度"),
+        ("volume", "体積"),
+        ("force", "力"),
+        ("energy", "エネルギー"),
+        ("power", "仕事率"),
+        ("pressure", "圧力"),
+        ("frequency", "周波数"),
+    ],
+    units: &[
+        unit_name!("length", "kilometer", "キロメートル", "キロメートル"),
+        unit_name!("length", "meter", "メートル", "メートル"),
+        unit_name!("length", "centimeter", "センチメートル", "センチメートル"),
+        unit_name!("length", "millimeter", "ミリメートル", "ミリメートル"),
+        unit_name!("mass", "kilogram", "キログラム", "キログラム"),
+        unit_name!("mass", "gram", "グラム", "グラム"),
+        unit_name!("time", "day", "日", "日"),
+        unit_name!("time", "hour", "時間", "時間"),
+        unit_name!("time", "minute", "分", "分"),
+        unit_name!("time", "second", "秒", "秒"),
+        unit_name!("electric current", "ampere", "アンペア", "アンペア"),
+        unit_name!("thermodynamic temperature", "kelvin", "ケルビン", "ケルビン"),
+        unit_name!("thermodynamic temperature", "degree Celsius", "セルシウス度", "セルシウス度"),
+        unit_name!("amount of substance", "mole", "モル", "モル"),
+        unit_name!("luminous intensity", "candela", "カンデラ", "カンデラ"),
+        unit_name!("velocity", "meter per second", "メートル毎秒", "メートル毎秒"),
+        unit_name!("velocity", "kilometer per hour", "キロメートル毎時", "キロメートル毎時"),
+        unit_name!("volume", "liter", "リットル", "リットル"),
+        unit_name!("force", "newton", "ニュートン", "ニュートン"),
+        unit_name!("energy", "joule", "ジュール", "ジュール"),
+        unit_name!("power", "watt", "ワット", "ワット"),
+        unit_name!("pressure", "pascal", "パスカル", "パスカル"),
+        unit_name!("frequency", "hertz", "ヘルツ", "ヘルツ"),
+    ],
+    fallback: None,
+};
+
+/// Writes a value and localized unit name. `value` is written using the precision of the
+/// formatter. The English `singular` or `plural` is written when the locale has no name for the
+/// unit.
+#[doc(hidden)]
+pub fn write_localized<L>(
+    f: &mut fmt::Formatter<'_>,
+    locale: &L,
+    value: &dyn fmt::Display,
+    quantity: &str,
+    singular: &str,
+    plural: &str,
+) -> fmt::Result
+where
+    L: Locale + ?Sized,
+{
+    let precision = f.precision();
+    let mut counter = Counter::default();
+
+    write_value(&mut counter, value, precision)?;
+
+    let numeric = counter.digits > 0 && !counter.other;
+    let mut operands = counter.operands;
+
+    operands.t = operands.f;
+
+    while operands.t != 0 && operands.t % 10 == 0 {
+        operands.t /= 10;
+    }
+
+    let category = if numeric { locale.plural_category(&operands) } else { PluralCategory::Other };
+    let name = locale.unit_name(quantity, singular, category).unwrap_or(
+        if numeric && english(&operands) == PluralCategory::One { singular } else { plural },
+    );
+    let mut localize = Localize {
+        f: &mut *f,
+        decimal_separator: locale.decimal_separator(),
+        grouping_separator: locale.grouping_separator().filter(|_| numeric),
+        remaining: counter.integer_digits,
+        fraction: !numeric,
+    };
+
+    write_value(&mut localize, value, precision)?;
+    f.write_str(locale.unit_separator())?;
+    f.write_str(name)
+}
+
+/// Writes a value with the given precision.
+fn write_value<W: Write>(
+    w: &mut W,
+    value: &dyn fmt::Display,
+    precision: Option<usize>,
+) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(w, "{:.*}", precision, value),
+        None => write!(w, "{}", value),
+    }
+}
+
+/// Writer determining the plural operands of a displayed number.
+#[derive(Default)]
+struct Counter {
+    operands: Operands,
+    digits: usize,
+    integer_digits: usize,
+    fraction: bool,
+    other: bool,
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        /// Appends a digit, offsetting values that exceed 10¹⁸ by 10¹⁸.
+        fn push(n: u64, digit: u32) -> u64 {
+            const LARGE: u64 = 1_000_000_000_000_000_000;
+
+            let next = (n % LARGE) * 10 + u64::from(digit);
+
+            if n >= LARGE || next >= LARGE {
+                LARGE + next % LARGE
+            } else {
+                next
+            }
+        }
+
+        for c in s.chars() {
+            match (c, c.to_digit(10)) {
+                (_, Some(digit)) if self.fraction => {
+                    self.operands.f = push(self.operands.f, digit);
+                    self.operands.v += 1;
+                    self.digits += 1;
+                }
+                (_, Some(digit)) => {
+                    self.operands.i = push(self.operands.i, digit);
+                    self.integer_digits += 1;
+                    self.digits += 1;
+                }
+                ('.', _) if !self.fraction => self.fraction = true,
+                ('-' | '+', _) if self.digits == 0 => {}
+                _ => self.other = true,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writer replacing the decimal separator and grouping integer digits.
+struct Localize<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+    remaining: usize,
+    fraction: bool,
+}
+
+impl<'a, 'b> Write for Localize<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '0'..='9' if !self.fraction => {
+                    self.f.write_char(c)?;
+                    self.remaining = self.remaining.saturating_sub(1);
+
+                    match self.grouping_separator {
+                        Some(separator) if self.remaining > 0 && self.remaining % 3 == 0 => {
+                            self.f.write_char(separator)?;
+                        }
+                        _ => {}
+                    }
+                }
+                '.' if !self.fraction => {
+                    self.f.write_char(self.decimal_separator)?;
+                    self.fraction = true;
+                }
+                _ => self.f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -61,10 +61,10 @@
 //! ## Features
 //! `uom` has multiple `Cargo` features for controlling available underlying storage types, the
 //! inclusion of the pre-built [International System of Units][si] (SI), support for
-//! [Serde][serde], and `no_std` functionality. The features are described below. `f32`, `f64`,
-//! `std`, and `si` are enabled by default. Features can be cherry-picked by using the
-//! `--no-default-features` and `--features "..."` flags when compiling `uom` or specifying
-//! features in Cargo.toml:
+//! [Serde][serde] and [Schemars][schemars], and `no_std` functionality. The features are
+//! described below. `f32`, `f64`, `std`, and `si` are enabled by default. Features can be
+//! cherry-picked by using the `--no-default-features` and `--features "..."` flags when compiling
+//! `uom` or specifying features in Cargo.toml:
 //!
 //! ```toml
 //! [dependencies]
@@ -81,6 +81,8 @@
 //!         "f32", "f64", # Floating point storage types.
 //!         "si", "std", # Built-in SI system and std library support.
 //!         "serde", # Serde support.
+//!         "schemars", # JSON Schema support.
+//!         "wire", # Compact binary encoding support.
 //!     ]
 //! }
 //! ```
@@ -100,9 +102,15 @@
 //!    `uom` with `no_std`. Enabled by default.
 //!  * `serde` -- Feature to enable support for serialization and deserialization of quantities with
 //!    the [Serde][serde] crate. Disabled by default.
+//!  * `schemars` -- Feature to enable generation of JSON Schemas for quantities with the
+//!    [Schemars][schemars] crate. Enables `serde`. Requires Rust 1.74 or later. Disabled by
+//!    default.
+//!  * `wire` -- Feature to enable a compact binary encoding of quantities with their dimension,
+//!    e.g. for telemetry links. See [`wire`]. Compatible with `no_std`. Disabled by default.
 //!
 //! [si]: https://jcgm.bipm.org/vim/en/1.16.html
 //! [serde]: https://serde.rs/
+//! [schemars]: https://graham.cool/schemars/
 //!
 //! ## Design
 //! Rather than working with [measurement units](https://jcgm.bipm.org/vim/en/1.9.html) (meter,
@@ -211,6 +219,13 @@ pub extern crate num_complex;
 #[cfg(feature = "serde")]
 pub extern crate serde_core as serde;
 
+#[doc(hidden)]
+#[cfg(feature = "schemars")]
+pub extern crate schemars;
+
+#[cfg(feature = "schemars")]
+extern crate alloc;
+
 #[doc(hidden)]
 pub extern crate typenum;
 
@@ -235,6 +250,11 @@ pub mod lib {
     #[cfg(feature = "std")]
     pub use std::*;
 
+    // Export `borrow` module from `alloc` when the `std` feature is not enabled. `Cow` is needed
+    // by `JsonSchema` implementations.
+    #[cfg(all(feature = "schemars", not(feature = "std")))]
+    pub use ::alloc::borrow;
+
     // Re-export `ops` module along with `typenum::ops` to provide all types in a single mod. This
     // allows the `system!` macro to reference all operations by the absolute path. Macro paths and
     // idents can't easily be combined without a `use` statement that pollutes the macro execution
@@ -470,6 +490,221 @@ pub trait ConversionFactor<V>:
     fn value(self) -> V;
 }
 
+/// Trait to create a [conversion factor][factor] from an `f64` value determined at runtime, e.g.
+/// the coefficient of a unit expression parsed from a string.
+///
+/// ## Generic Parameters
+/// * `V`: Underlying storage type trait is implemented for.
+///
+/// [factor]: https://jcgm.bipm.org/vim/en/1.24.html
+pub trait RuntimeConversion<V>: Conversion<V> {
+    /// Creates a conversion factor from the given `f64` value. Returns `None` if the value can't
+    /// be represented by the conversion factor type.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn factor_from_f64(value: f64) -> Option<Self::T>;
+
+    /// Creates a conversion factor from the given exact factor. Returns `None` if the factor can't
+    /// be represented by the conversion factor type.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn factor_from_exact(value: ExactFactor) -> Option<Self::T>;
+
+    /// Creates the conversion factor of a unit expression from the `f64` coefficient of the
+    /// expression or from `exact`, which multiplies the conversion factors of the units of the
+    /// expression. Floating point storage types use the coefficient, which is computed with at
+    /// least their precision, while other storage types use the exact factor.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn factor_from_expression(
+        coefficient: f64,
+        exact: &mut dyn FnMut() -> Option<Self::T>,
+    ) -> Option<Self::T>;
+}
+
+/// Exact rational [conversion factor][factor] of a unit, e.g. 127/5000 to convert inches to
+/// meters. Given after a unit's floating point coefficient or constant in the [`quantity!`] and
+/// [`unit!`] macros, exact factors are used verbatim by integer, rational, and big rational
+/// underlying storage types while floating point storage types keep using the literal. Exact
+/// factors are evaluated at compile time and overflow is a compile error.
+///
+#[cfg_attr(all(feature = "si", feature = "i64"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "i64")), doc = " ```rust,ignore")]
+/// # use uom::si::i64::*;
+/// # use uom::si::length::{inch, meter};
+/// use uom::ExactFactor;
+///
+/// const KILO_SQUARED: ExactFactor = ExactFactor::pow10(3).mul(ExactFactor::pow10(3));
+///
+/// assert_eq!(1_000_000, KILO_SQUARED.numer());
+/// assert_eq!(127, Length::new::<inch>(5_000).get::<meter>());
+/// ```
+///
+/// [factor]: https://jcgm.bipm.org/vim/en/1.24.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ExactFactor {
+    numer: i128,
+    denom: i128,
+}
+
+impl ExactFactor {
+    /// Creates a new factor from the given numerator and denominator. The factor is reduced to
+    /// lowest terms with a positive denominator.
+    ///
+    /// # Panics
+    /// Panics if `denom` is zero.
+    #[must_use]
+    pub const fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "denominator of an exact factor must not be zero");
+
+        let gcd = gcd(numer, denom);
+        let sign = if denom < 0 { -1 } else { 1 };
+
+        ExactFactor { numer: sign * numer / gcd, denom: sign * denom / gcd }
+    }
+
+    /// Creates a new factor equal to `10^exp`, e.g. `pow10(-3)` for the SI prefix milli.
+    #[must_use]
+    pub const fn pow10(exp: i32) -> Self {
+        let power = 10_i128.pow(exp.unsigned_abs());
+
+        if exp < 0 {
+            ExactFactor::new(1, power)
+        } else {
+            ExactFactor::new(power, 1)
+        }
+    }
+
+    /// Multiplies two factors, e.g. to combine prefixes.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, rhs: Self) -> Self {
+        let a = gcd(self.numer, rhs.denom);
+        let b = gcd(rhs.numer, self.denom);
+
+        ExactFactor::new((self.numer / a) * (rhs.numer / b), (self.denom / b) * (rhs.denom / a))
+    }
+
+    /// Divides two factors.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.mul(ExactFactor::new(rhs.denom, rhs.numer))
+    }
+
+    /// Numerator of the factor in lowest terms.
+    #[must_use]
+    pub const fn numer(self) -> i128 {
+        self.numer
+    }
+
+    /// Denominator of the factor in lowest terms. Always positive.
+    #[must_use]
+    pub const fn denom(self) -> i128 {
+        self.denom
+    }
+}
+
+/// Greatest common divisor of `a` and `b`. Returns 1 when both are zero.
+const fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        let r = a % b;
+
+        a = b;
+        b = r;
+    }
+
+    if a == 0 {
+        1
+    } else {
+        a
+    }
+}
+
+/// Rounding mode used when a value converted between units isn't exactly representable by the
+/// underlying storage type, e.g. `1 cm` as an integer number of meters. Floating point and
+/// rational underlying storage types represent converted values without rounding. Also used to
+/// round the value of the last unit when displaying a quantity as a sum of units, e.g. "5 ft 3
+/// in".
+///
+#[cfg_attr(all(feature = "si", feature = "i32"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "i32")), doc = " ```rust,ignore")]
+/// # use uom::si::i32::*;
+/// # use uom::si::length::{centimeter, kilometer, meter};
+/// use uom::RoundingMode;
+///
+/// let l = Length::new_rounded::<centimeter>(150, RoundingMode::HalfEven);
+///
+/// assert_eq!(2, l.get::<meter>());
+/// assert_eq!(1, l.get_rounded::<kilometer>(RoundingMode::Ceil));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards zero. Matches the behavior of `new` and `get`.
+    Trunc,
+
+    /// Round towards negative infinity.
+    Floor,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round to the nearest value. Half-way cases are rounded to the nearest even value.
+    HalfEven,
+}
+
+/// Error returned when a value converted between units isn't exactly representable by the
+/// underlying storage type.
+///
+/// ## Generic Parameters
+/// * `T`: [Conversion factor][factor] type of the underlying storage type, e.g. `Ratio<i32>` for
+///   `i32`.
+///
+#[cfg_attr(all(feature = "si", feature = "i32"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "i32")), doc = " ```rust,ignore")]
+/// # use uom::si::i32::*;
+/// # use uom::si::length::centimeter;
+/// use uom::num_rational::Ratio;
+/// use uom::ConversionError;
+///
+/// let e = Length::try_new::<centimeter>(150).unwrap_err();
+///
+/// assert_eq!(ConversionError::Inexact { remainder: Ratio::new(1, 2) }, e);
+/// ```
+///
+/// [factor]: https://jcgm.bipm.org/vim/en/1.24.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConversionError<T> {
+    /// An intermediate result overflowed while applying the conversion factor.
+    Overflow,
+
+    /// The converted value has a fractional part that the underlying storage type can't
+    /// represent.
+    Inexact {
+        /// Exact remainder lost by rounding the converted value towards zero.
+        remainder: T,
+    },
+}
+
+impl<T> lib::fmt::Display for ConversionError<T>
+where
+    T: lib::fmt::Display,
+{
+    fn fmt(&self, f: &mut lib::fmt::Formatter<'_>) -> lib::fmt::Result {
+        match self {
+            ConversionError::Overflow => write!(f, "unit conversion overflowed"),
+            ConversionError::Inexact { remainder } => {
+                write!(f, "unit conversion is inexact, remainder {remainder}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> lib::error::Error for ConversionError<T> where T: lib::fmt::Debug + lib::fmt::Display {}
+
 /// Helper trait to identify the zero value of a type at compile time.
 ///
 #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
@@ -536,6 +771,28 @@ storage_types! {
         }
     }
 
+    impl crate::RuntimeConversion<Self> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            <Self::T as crate::num::FromPrimitive>::from_f64(value)
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::from_i128(value.numer())? / Self::T::from_i128(value.denom())?)
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            coefficient: f64,
+            _exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            Self::factor_from_f64(coefficient)
+        }
+    }
+
     impl crate::ConstZero for V {
         const ZERO: Self = 0.0;
     }
@@ -565,6 +822,28 @@ storage_types! {
         }
     }
 
+    impl crate::RuntimeConversion<V> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            <Self::T as crate::num::FromPrimitive>::from_f64(value)
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::new(V::from_i128(value.numer())?, V::from_i128(value.denom())?))
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            _coefficient: f64,
+            exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            exact()
+        }
+    }
+
     impl crate::ConstZero for V {
         const ZERO: Self = 0;
     }
@@ -597,6 +876,33 @@ storage_types! {
             self.to_integer()
         }
     }
+
+    impl crate::RuntimeConversion<V> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            let ratio = crate::num::rational::BigRational::from_float(value)?;
+
+            Some(Self::T::new_raw(
+                V::try_from(ratio.numer().clone()).ok()?,
+                V::try_from(ratio.denom().clone()).ok()?,
+            ))
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::new(V::from_i128(value.numer())?, V::from_i128(value.denom())?))
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            _coefficient: f64,
+            exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            exact()
+        }
+    }
 }
 
 storage_types! {
@@ -622,6 +928,28 @@ storage_types! {
             self
         }
     }
+
+    impl crate::RuntimeConversion<V> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            <Self::T as crate::num::FromPrimitive>::from_f64(value)
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::from_i128(value.numer())? / Self::T::from_i128(value.denom())?)
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            _coefficient: f64,
+            exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            exact()
+        }
+    }
 }
 
 storage_types! {
@@ -651,6 +979,28 @@ storage_types! {
             self
         }
     }
+
+    impl crate::RuntimeConversion<V> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            <Self::T as crate::num::FromPrimitive>::from_f64(value)
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::from_i128(value.numer())? / Self::T::from_i128(value.denom())?)
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            _coefficient: f64,
+            exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            exact()
+        }
+    }
 }
 
 storage_types! {
@@ -687,11 +1037,36 @@ storage_types! {
             V::new(self, 0.0)
         }
     }
+
+    impl crate::RuntimeConversion<V> for V {
+        #[inline(always)]
+        fn factor_from_f64(value: f64) -> Option<Self::T> {
+            <Self::T as crate::num::FromPrimitive>::from_f64(value)
+        }
+
+        #[inline(always)]
+        fn factor_from_exact(value: crate::ExactFactor) -> Option<Self::T> {
+            use crate::num::FromPrimitive;
+
+            Some(Self::T::from_i128(value.numer())? / Self::T::from_i128(value.denom())?)
+        }
+
+        #[inline(always)]
+        fn factor_from_expression(
+            coefficient: f64,
+            _exact: &mut dyn FnMut() -> Option<Self::T>,
+        ) -> Option<Self::T> {
+            Self::factor_from_f64(coefficient)
+        }
+    }
 }
 
 /// Utilities for formatting and printing quantities.
 pub mod fmt {
+    pub mod locale;
+
     /// An enum to specify the display style to use.
+    #[non_exhaustive]
     #[derive(Clone, Copy, Debug)]
     pub enum DisplayStyle {
         /// Display the value and a unit abbreviation, e.g. "1.0 m", "327 s".
@@ -700,46 +1075,559 @@ pub mod fmt {
         /// Display the value and full unit name (pluralized as appropriate),
         /// e.g. "1 kilogram", "756 feet".
         Description,
+
+        /// Display the quantity as [siunitx][siunitx] LaTeX markup, e.g.
+        /// `\qty{9.81}{\meter\per\second\squared}`. The unit's markup is used when specified,
+        /// otherwise unit macros are generated from the prefixes and symbols of the abbreviation,
+        /// e.g. `\kilo\meter\per\hour` for `km/h`. Abbreviations without matching macros are
+        /// written as literal units using `\micro`, `\ohm`, and `\degree`, e.g. `\degree F`.
+        ///
+        /// [siunitx]: https://ctan.org/pkg/siunitx
+        Siunitx,
+
+        /// Display the value and unit abbreviation as LaTeX math, e.g. `9.81\,\mathrm{m/s^{2}}`.
+        Latex,
+
+        /// Display the value and unit abbreviation as HTML separated by a non-breaking space, e.g.
+        /// `9.81&nbsp;m/s<sup>2</sup>`.
+        Html,
+
+        /// Display the value and unit abbreviation as a MathML `<mrow>` element, e.g.
+        /// `<mrow><mn>1</mn><mo>&#x2062;</mo><mi mathvariant="normal">m</mi></mrow>`.
+        MathMl,
     }
-}
 
-/// Unicode string slice manipulation for quantities.
-pub mod str {
-    use crate::lib::fmt::{self, Display, Formatter};
+    /// An enum to specify which prefixed units are considered when displaying a quantity with an
+    /// automatically selected [SI prefix][prefix], e.g. "3 mW" or "3 GW". The unit with the
+    /// largest prefix that keeps the displayed value greater than or equal to one is used.
+    ///
+    /// ## Generic Parameters
+    /// * `N`: Unit enum of the quantity.
+    ///
+    /// [prefix]: https://jcgm.bipm.org/vim/en/1.26.html
+    #[derive(Clone, Copy, Debug)]
+    pub enum Prefixes<'a, N> {
+        /// The unit and all units defined as the unit with an SI prefix, e.g. "hW" or "cW".
+        All,
 
-    /// Represents an error encountered while parsing a string into a `Quantity`.
-    #[allow(missing_copy_implementations)]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum ParseQuantityError {
-        /// No separators (spaces) were encountered.
-        NoSeparator,
+        /// The unit and all units defined as the unit with an SI prefix that is a power of
+        /// 1000, e.g. "kW" or "mW", but not "hW" or "cW". Values are displayed in the range
+        /// [1, 1000).
+        Engineering,
 
-        /// An error occurred while parsing the value (first) portion of the string.
-        ///
-        /// Due to exhaustiveness and type system limitations, this variant does not encode
-        /// the underlying parse error.
-        ValueParseError,
+        /// Only the given units.
+        Only(&'a [N]),
+    }
 
-        /// The unit used wasn't found for this quantity.
-        ///
-        /// ### Notes
-        /// For now, only abbreviations are supported, so this error may be encountered even if the
-        /// unit name (description) is correct.
-        UnknownUnit,
+    /// An enum to specify the notation of base units when displaying a quantity in base units,
+    /// e.g. for an acceleration multiplied by a mass.
+    #[derive(Clone, Copy, Debug)]
+    pub enum Notation {
+        /// Product of units with superscript exponents, e.g. "m·kg·s⁻²".
+        Superscript,
+
+        /// ASCII fraction with `^` exponents, e.g. "m*kg/s^2".
+        Ascii,
+
+        /// Fraction with superscript exponents, e.g. "m·kg/s²".
+        Fraction,
     }
 
-    impl Display for ParseQuantityError {
-        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-            use ParseQuantityError::{NoSeparator, UnknownUnit, ValueParseError};
+    /// Writes the product of unit abbreviations raised to the given exponents using the given
+    /// notation. Units with an exponent of zero are omitted.
+    #[doc(hidden)]
+    pub fn write_units(
+        f: &mut crate::lib::fmt::Formatter<'_>,
+        units: &[(&str, i32)],
+        notation: Notation,
+    ) -> crate::lib::fmt::Result {
+        let (separator, superscript) = match notation {
+            Notation::Superscript | Notation::Fraction => ("·", true),
+            Notation::Ascii => ("*", false),
+        };
+        let fraction = !matches!(notation, Notation::Superscript);
+        let write = |f: &mut crate::lib::fmt::Formatter<'_>, units: &[(&str, i32)], sign: i32| {
+            let mut first = true;
+
+            for &(unit, exponent) in units {
+                let exponent = exponent * sign;
+
+                if exponent <= 0 && (fraction || exponent == 0) {
+                    continue;
+                }
+
+                if !first {
+                    f.write_str(separator)?;
+                }
+
+                first = false;
+                f.write_str(unit)?;
+
+                match exponent {
+                    1 => {}
+                    _ if superscript => write!(f, "{}", Superscript(exponent))?,
+                    _ => write!(f, "^{}", exponent)?,
+                }
+            }
+
+            Ok(())
+        };
+        let numerator = units.iter().filter(|&&(_, e)| e > 0 || (!fraction && e != 0)).count();
+        let denominator = units.iter().filter(|&&(_, e)| fraction && e < 0).count();
+
+        write(f, units, 1)?;
+
+        if denominator > 0 {
+            f.write_str(if numerator == 0 { "1/" } else { "/" })?;
 
-            match *self {
-                NoSeparator => write!(f, "no space between quantity and units"),
-                ValueParseError => write!(f, "error parsing unit quantity"),
-                UnknownUnit => write!(f, "unrecognized unit of measure"),
+            if denominator > 1 {
+                f.write_str("(")?;
+                write(f, units, -1)?;
+                f.write_str(")")
+            } else {
+                write(f, units, -1)
             }
+        } else {
+            Ok(())
         }
     }
 
-    #[cfg(feature = "std")]
-    impl crate::lib::error::Error for ParseQuantityError {}
+    /// Integer displayed with superscript digits, e.g. "⁻²".
+    struct Superscript(i32);
+
+    impl crate::lib::fmt::Display for Superscript {
+        fn fmt(&self, f: &mut crate::lib::fmt::Formatter<'_>) -> crate::lib::fmt::Result {
+            const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+            let n = self.0.unsigned_abs();
+            let mut divisor = 1;
+
+            if self.0 < 0 {
+                crate::lib::fmt::Write::write_char(f, '⁻')?;
+            }
+
+            while n / divisor >= 10 {
+                divisor *= 10;
+            }
+
+            while divisor > 0 {
+                crate::lib::fmt::Write::write_char(f, DIGITS[(n / divisor % 10) as usize])?;
+                divisor /= 10;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Writes any markup preceding the value of a quantity displayed with the given style.
+    #[doc(hidden)]
+    pub fn write_value_start(
+        f: &mut crate::lib::fmt::Formatter<'_>,
+        style: DisplayStyle,
+    ) -> crate::lib::fmt::Result {
+        match style {
+            DisplayStyle::Siunitx => f.write_str("\\qty{"),
+            DisplayStyle::MathMl => f.write_str("<mrow><mn>"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Writes the unit following the value of a quantity displayed with the given style. The
+    /// abbreviation is rendered for markup styles unless `markup` is given for
+    /// [`DisplayStyle::Siunitx`].
+    #[doc(hidden)]
+    pub fn write_unit(
+        f: &mut crate::lib::fmt::Formatter<'_>,
+        style: DisplayStyle,
+        abbreviation: &str,
+        description: &str,
+        markup: Option<&str>,
+    ) -> crate::lib::fmt::Result {
+        match (style, markup) {
+            (DisplayStyle::Abbreviation, _) => write!(f, " {}", abbreviation),
+            (DisplayStyle::Description, _) => write!(f, " {}", description),
+            (DisplayStyle::Siunitx, Some(markup)) => write!(f, "}}{{{}}}", markup),
+            (DisplayStyle::Siunitx, None) => {
+                f.write_str("}{")?;
+
+                // Check that every token has a macro before writing any of them.
+                if write_siunitx(&mut Discard, abbreviation, description).is_ok() {
+                    write_siunitx(f, abbreviation, description)?;
+                } else {
+                    write_tokens(f, style, abbreviation)?;
+                }
+
+                f.write_str("}")
+            }
+            (DisplayStyle::Latex, _) => {
+                f.write_str("\\,\\mathrm{")?;
+                write_tokens(f, style, abbreviation)?;
+                f.write_str("}")
+            }
+            (DisplayStyle::Html, _) => {
+                f.write_str("&nbsp;")?;
+                write_tokens(f, style, abbreviation)
+            }
+            (DisplayStyle::MathMl, _) => {
+                f.write_str("</mn><mo>&#x2062;</mo>")?;
+                write_tokens(f, style, abbreviation)?;
+                f.write_str("</mrow>")
+            }
+        }
+    }
+
+    /// siunitx unit macros along with the symbol of the unit and a name that the unit's
+    /// description must contain, e.g. `"km"` is only written as `\kilo\meter` when the description
+    /// contains both `"kilo"` and `"meter"`. This prevents abbreviations such as `ft` from being
+    /// interpreted as `\femto\tonne`.
+    const SIUNITX_UNITS: [(&str, &str, &str); 41] = [
+        ("m", "\\meter", "meter"),
+        ("g", "\\gram", "gram"),
+        ("s", "\\second", "second"),
+        ("A", "\\ampere", "ampere"),
+        ("K", "\\kelvin", "kelvin"),
+        ("mol", "\\mole", "mole"),
+        ("cd", "\\candela", "candela"),
+        ("Hz", "\\hertz", "hertz"),
+        ("N", "\\newton", "newton"),
+        ("Pa", "\\pascal", "pascal"),
+        ("J", "\\joule", "joule"),
+        ("W", "\\watt", "watt"),
+        ("C", "\\coulomb", "coulomb"),
+        ("V", "\\volt", "volt"),
+        ("F", "\\farad", "farad"),
+        ("Ω", "\\ohm", "ohm"),
+        ("S", "\\siemens", "siemens"),
+        ("Wb", "\\weber", "weber"),
+        ("T", "\\tesla", "tesla"),
+        ("H", "\\henry", "henr"),
+        ("lm", "\\lumen", "lumen"),
+        ("lx", "\\lux", "lux"),
+        ("Bq", "\\becquerel", "becquerel"),
+        ("Gy", "\\gray", "gray"),
+        ("Sv", "\\sievert", "sievert"),
+        ("kat", "\\katal", "katal"),
+        ("rad", "\\radian", "radian"),
+        ("sr", "\\steradian", "steradian"),
+        ("°C", "\\degreeCelsius", "Celsius"),
+        ("°", "\\degree", "degree"),
+        ("L", "\\liter", "liter"),
+        ("l", "\\liter", "liter"),
+        ("t", "\\tonne", "ton"),
+        ("min", "\\minute", "minute"),
+        ("h", "\\hour", "hour"),
+        ("d", "\\day", "day"),
+        ("ha", "\\hectare", "hectare"),
+        ("bar", "\\bar", "bar"),
+        ("eV", "\\electronvolt", "electronvolt"),
+        ("B", "\\bel", "bel"),
+        ("Np", "\\neper", "neper"),
+    ];
+
+    /// Writer that discards its output.
+    struct Discard;
+
+    impl crate::lib::fmt::Write for Discard {
+        fn write_str(&mut self, _: &str) -> crate::lib::fmt::Result {
+            Ok(())
+        }
+    }
+
+    /// Writes a unit abbreviation as siunitx macros, e.g. `\kilo\meter\per\hour` for `km/h`.
+    /// Returns an error when a token of the abbreviation has no matching macro.
+    fn write_siunitx<W: crate::lib::fmt::Write>(
+        w: &mut W,
+        abbreviation: &str,
+        description: &str,
+    ) -> crate::lib::fmt::Result {
+        for token in Tokens(abbreviation) {
+            match token {
+                Token::Text(s) => write_siunitx_unit(w, s, description)?,
+                Token::Superscript("²") => w.write_str("\\squared")?,
+                Token::Superscript("³") => w.write_str("\\cubed")?,
+                Token::Superscript(s) => {
+                    w.write_str("\\tothe{")?;
+
+                    if let Some(s) = s.strip_prefix('⁻') {
+                        w.write_str("-")?;
+                        write_digits(w, s)?;
+                    } else {
+                        write_digits(w, s)?;
+                    }
+
+                    w.write_str("}")?;
+                }
+                Token::Operator('/') => w.write_str("\\per")?,
+                Token::Operator('(' | ')') | Token::Subscript(_) => {
+                    return Err(crate::lib::fmt::Error)
+                }
+                Token::Operator(_) | Token::Space => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single, possibly prefixed, unit symbol as siunitx macros, e.g. `\micro\meter`
+    /// for `µm`.
+    fn write_siunitx_unit<W: crate::lib::fmt::Write>(
+        w: &mut W,
+        symbol: &str,
+        description: &str,
+    ) -> crate::lib::fmt::Result {
+        let unit = |symbol: &str| {
+            SIUNITX_UNITS
+                .iter()
+                .find(|&&(s, _, name)| s == symbol && description.contains(name))
+                .map(|&(_, markup, _)| markup)
+        };
+
+        if let Some(markup) = unit(symbol) {
+            return w.write_str(markup);
+        }
+
+        crate::str::PREFIXES
+            .iter()
+            .filter(|prefix| prefix.symbol != "u" && description.contains(prefix.name))
+            .find_map(|prefix| {
+                symbol.strip_prefix(prefix.symbol).and_then(unit).map(|markup| (prefix, markup))
+            })
+            .map_or(Err(crate::lib::fmt::Error), |(prefix, markup)| {
+                write!(w, "\\{}{}", prefix.name, markup)
+            })
+    }
+
+    /// Writes the tokens of a unit abbreviation using the given markup style.
+    fn write_tokens(
+        f: &mut crate::lib::fmt::Formatter<'_>,
+        style: DisplayStyle,
+        abbreviation: &str,
+    ) -> crate::lib::fmt::Result {
+        let mut tokens = Tokens(abbreviation).peekable();
+
+        while let Some(token) = tokens.next() {
+            let script = match (token, tokens.peek()) {
+                (Token::Superscript(s), _) => Some(("msup", s)),
+                (Token::Subscript(s), _) => Some(("msub", s)),
+                (_, Some(&Token::Superscript(s))) => Some(("msup", s)),
+                (_, Some(&Token::Subscript(s))) => Some(("msub", s)),
+                _ => None,
+            };
+
+            match (style, script) {
+                (DisplayStyle::MathMl, Some((element, s))) => {
+                    write!(f, "<{}>", element)?;
+
+                    match token {
+                        Token::Superscript(_) | Token::Subscript(_) => f.write_str("<mrow/>")?,
+                        _ => {
+                            write_token(f, style, token)?;
+                            let _ = tokens.next();
+                        }
+                    }
+
+                    match s.strip_prefix('⁻') {
+                        Some(s) => {
+                            f.write_str("<mrow><mo>&minus;</mo><mn>")?;
+                            write_digits(f, s)?;
+                            f.write_str("</mn></mrow>")?;
+                        }
+                        None => {
+                            f.write_str("<mn>")?;
+                            write_digits(f, s)?;
+                            f.write_str("</mn>")?;
+                        }
+                    }
+
+                    write!(f, "</{}>", element)?;
+                }
+                _ => write_token(f, style, token)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single unit abbreviation token using the given markup style.
+    fn write_token(
+        f: &mut crate::lib::fmt::Formatter<'_>,
+        style: DisplayStyle,
+        token: Token<'_>,
+    ) -> crate::lib::fmt::Result {
+        let latex = matches!(style, DisplayStyle::Siunitx | DisplayStyle::Latex);
+
+        match token {
+            Token::Text(s) => {
+                if let DisplayStyle::MathMl = style {
+                    f.write_str("<mi mathvariant=\"normal\">")?;
+                }
+
+                let mut chars = s.chars().peekable();
+
+                while let Some(c) = chars.next() {
+                    // Separate siunitx macros from following letters, e.g. `\degree F`.
+                    let space = match chars.peek() {
+                        Some(c) if c.is_alphabetic() => " ",
+                        _ => "",
+                    };
+
+                    match (c, style) {
+                        ('µ' | 'μ', DisplayStyle::Siunitx) => write!(f, "\\micro{}", space)?,
+                        ('Ω', DisplayStyle::Siunitx) => write!(f, "\\ohm{}", space)?,
+                        ('°', DisplayStyle::Siunitx) => write!(f, "\\degree{}", space)?,
+                        ('µ' | 'μ', DisplayStyle::Latex) => f.write_str("\\mu{}")?,
+                        ('µ' | 'μ', _) => f.write_str("&micro;")?,
+                        ('Ω', DisplayStyle::Latex) => f.write_str("\\Omega{}")?,
+                        ('°', DisplayStyle::Latex) => f.write_str("{}^{\\circ}")?,
+                        ('%' | '#' | '&' | '_' | '{' | '}', _) if latex => write!(f, "\\{}", c)?,
+                        ('~' | '^' | '\\', _) if latex => write!(f, "\\char{}{{}}", c as u32)?,
+                        ('&', _) => f.write_str("&amp;")?,
+                        ('<', _) if !latex => f.write_str("&lt;")?,
+                        ('>', _) if !latex => f.write_str("&gt;")?,
+                        _ => crate::lib::fmt::Write::write_char(f, c)?,
+                    }
+                }
+
+                if let DisplayStyle::MathMl = style {
+                    f.write_str("</mi>")?;
+                }
+
+                Ok(())
+            }
+            Token::Superscript(s) | Token::Subscript(s) => {
+                let (start, end) = match (style, token) {
+                    (DisplayStyle::Html, Token::Superscript(_)) => ("<sup>", "</sup>"),
+                    (DisplayStyle::Html, _) => ("<sub>", "</sub>"),
+                    (_, Token::Superscript(_)) => ("^{", "}"),
+                    _ => ("_{", "}"),
+                };
+
+                f.write_str(start)?;
+
+                if let Some(s) = s.strip_prefix('⁻') {
+                    f.write_str(if latex { "-" } else { "&minus;" })?;
+                    write_digits(f, s)?;
+                } else {
+                    write_digits(f, s)?;
+                }
+
+                f.write_str(end)
+            }
+            Token::Operator(c) => match (c, style) {
+                ('/', DisplayStyle::MathMl) => f.write_str("<mo>/</mo>"),
+                ('(' | ')', DisplayStyle::MathMl) => write!(f, "<mo>{}</mo>", c),
+                (_, DisplayStyle::MathMl) => f.write_str("<mo>&middot;</mo>"),
+                ('/' | '(' | ')', _) => write!(f, "{}", c),
+                (_, DisplayStyle::Siunitx) => f.write_str("."),
+                (_, DisplayStyle::Latex) => f.write_str("\\cdot{}"),
+                _ => f.write_str("&middot;"),
+            },
+            Token::Space => match style {
+                DisplayStyle::Siunitx => f.write_str("~"),
+                DisplayStyle::Latex => f.write_str("\\ "),
+                DisplayStyle::MathMl => f.write_str("<mspace width=\"0.25em\"/>"),
+                _ => f.write_str("&nbsp;"),
+            },
+        }
+    }
+
+    /// Writes superscript or subscript digits as ASCII digits.
+    fn write_digits<W: crate::lib::fmt::Write>(f: &mut W, s: &str) -> crate::lib::fmt::Result {
+        for c in s.chars() {
+            match c {
+                '¹' => f.write_str("1")?,
+                '²' => f.write_str("2")?,
+                '³' => f.write_str("3")?,
+                '⁰' | '⁴'..='⁹' => write!(f, "{}", c as u32 - '⁰' as u32)?,
+                '₀'..='₉' => write!(f, "{}", c as u32 - '₀' as u32)?,
+                _ => crate::lib::fmt::Write::write_char(f, c)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Token of a unit abbreviation.
+    #[derive(Clone, Copy, Debug)]
+    enum Token<'a> {
+        /// Run of text, e.g. "km".
+        Text(&'a str),
+
+        /// Run of superscript characters, e.g. "⁻²".
+        Superscript(&'a str),
+
+        /// Run of subscript characters, e.g. "₃₉".
+        Subscript(&'a str),
+
+        /// Product, quotient, or parenthesis.
+        Operator(char),
+
+        /// Space between text that is not adjacent to a product or quotient.
+        Space,
+    }
+
+    /// Iterator over the tokens of a unit abbreviation.
+    struct Tokens<'a>(&'a str);
+
+    impl<'a> Iterator for Tokens<'a> {
+        type Item = Token<'a>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            fn is_product(c: char) -> bool {
+                matches!(c, '/' | '·' | '⋅' | '*')
+            }
+
+            fn class(c: char) -> u8 {
+                match c {
+                    '⁻' | '¹' | '²' | '³' | '⁰' | '⁴'..='⁹' => 1,
+                    '₀'..='₉' => 2,
+                    ' ' | '/' | '·' | '⋅' | '*' | '(' | ')' => 3,
+                    _ => 0,
+                }
+            }
+
+            let s = self.0;
+            let c = s.chars().next()?;
+
+            if c == ' ' {
+                let rest = s.trim_start_matches(' ');
+
+                self.0 = rest;
+
+                return match rest.chars().next() {
+                    Some(c) if !is_product(c) => Some(Token::Space),
+                    Some(_) => self.next(),
+                    None => None,
+                };
+            }
+
+            if class(c) == 3 {
+                self.0 = &s[c.len_utf8()..];
+
+                if is_product(c) {
+                    self.0 = self.0.trim_start_matches(' ');
+                }
+
+                return Some(Token::Operator(c));
+            }
+
+            let end = s.find(|n| class(n) != class(c)).unwrap_or(s.len());
+            let (token, rest) = s.split_at(end);
+
+            self.0 = rest;
+
+            Some(match class(c) {
+                1 => Token::Superscript(token),
+                2 => Token::Subscript(token),
+                _ => Token::Text(token),
+            })
+        }
+    }
 }
+
+pub mod registry;
+
+pub mod str;
+
+#[cfg(feature = "wire")]
+pub mod wire;
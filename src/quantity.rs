@@ -17,12 +17,21 @@
 /// * `$unit`: Unit name (e.g. `meter`, `foot`).
 /// * `$coefficient`: Conversion coefficient from the unit to the base unit of the quantity (e.g.
 ///   `3.048_E-1` to convert `foot` to `meter`, `1.0_E0` to convert `celsius` to `kelvin`).
+/// * `$exact`: Optional exact rational coefficient, an [`ExactFactor`](crate::ExactFactor)
+///   constant expression, used verbatim by integer, rational, and big rational underlying storage
+///   types instead of `$coefficient` (e.g. `ExactFactor::new(3_048, 10_000)` to convert `foot` to
+///   `meter`). Floating point underlying storage types always use `$coefficient`.
 /// * `$constant`: Optional conversion constant factor from the unit to the base unit of the
 ///   quantity (e.g. `273.15_E0` to convert `celsius` to `kelvin`). Note that using a unit with a
 ///   non-zero constant factor is not currently supported as a base unit.
+/// * `$exact_constant`: Optional exact rational constant, an
+///   [`ExactFactor`](crate::ExactFactor) constant expression, used like `$exact`.
 /// * `$abbreviation`: Unit abbreviation (e.g. `"m"`).
 /// * `$singular`: Singular unit description (e.g. `"meter"`).
 /// * `$plural`: Plural unit description (e.g. `"meters"`).
+/// * `$markup`: Optional [siunitx][siunitx] unit markup (e.g. `r"\meter"`) used by
+///   [`DisplayStyle::Siunitx`](crate::fmt::DisplayStyle::Siunitx). When not specified the markup
+///   is generated from the abbreviation.
 ///
 /// An example invocation is given below for the quantity of length in a meter-kilogram-second
 /// system. The `#[macro_use]` attribute must be used when including the `uom` crate to make the
@@ -43,7 +52,7 @@
 ///         dimension: Q<P1 /*length*/, Z0 /*mass*/, Z0 /*time*/>;
 ///         units {
 ///             @meter: 1.0E0; "m", "meter", "meters";
-///             @foot: 3.048E-1; "ft", "foot", "feet";
+///             @foot: 3.048E-1 => uom::ExactFactor::new(3_048, 10_000); "ft", "foot", "feet";
 ///         }
 ///     }
 /// }
@@ -94,6 +103,7 @@
 /// [quantity]: https://jcgm.bipm.org/vim/en/1.1.html
 /// [measurement]: https://jcgm.bipm.org/vim/en/1.9.html
 /// [kind]: https://jcgm.bipm.org/vim/en/1.2.html
+/// [siunitx]: https://ctan.org/pkg/siunitx
 #[macro_export]
 macro_rules! quantity {
     (
@@ -101,8 +111,9 @@ macro_rules! quantity {
         $(#[$dim_attr:meta])* dimension: $system:ident<$($dimension:ident),+>;
         $(kind: $kind:ty;)?
         units {
-            $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(, $constant:expr)?;
-                $abbreviation:expr, $singular:expr, $plural:expr;)+
+            $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(=> $exact:expr)?
+                $(, $constant:expr $(=> $exact_constant:expr)?)?;
+                $abbreviation:expr, $singular:expr, $plural:expr $(, $markup:expr)?;)+
         }
     ) => {
         mod __system {
@@ -142,9 +153,22 @@ macro_rules! quantity {
             fn is_valid() -> bool;}
         }
 
+        /// Trait to identify measurement units of the quantity selected at runtime: [`Units`] and
+        /// units registered in a [`UnitRegistry`]($crate::registry::UnitRegistry). See
+        /// [`RuntimeUnit`](__system::RuntimeUnit).
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Underlying storage type trait is implemented for.
+        pub trait RuntimeUnit<V>: __system::RuntimeUnit<V>
+        where
+            V: $crate::Conversion<V>,
+        {
+        }
+
         unit_units! {
-            $($(#[$unit_attr])* @$unit: $coefficient $(, $constant)?;
-                $abbreviation, $singular, $plural;)+
+            $($(#[$unit_attr])* @$unit: $coefficient $(=> $exact)?
+                $(, $constant $(=> $exact_constant)?)?;
+                $abbreviation, $singular, $plural $(, $markup)?;)+
         }
 
         /// Quantity description.
@@ -155,11 +179,36 @@ macro_rules! quantity {
             $description
         }
 
+        /// Creates a [`UnitRegistry`]($crate::registry::UnitRegistry) for the quantity that
+        /// stores custom units in `storage`. Any units already in `storage` are removed.
+        #[must_use]
+        #[allow(dead_code)]
+        pub fn registry<'a, V, S>(
+            storage: S,
+        ) -> $crate::registry::UnitRegistry<'a, Dimension, V, S>
+        where
+            S: AsRef<[Option<$crate::registry::CustomUnit<'a, Dimension, V>>]>
+                + AsMut<[Option<$crate::registry::CustomUnit<'a, Dimension, V>>]>,
+        {
+            $crate::registry::UnitRegistry::new(storage, description(), self::str::unit_names)
+        }
+
+        /// Localized quantity description. The English description is returned when the locale
+        /// has no description for the quantity.
+        #[must_use = "method returns a new value"]
+        #[allow(dead_code)]
+        pub fn localized_description<L>(locale: &L) -> &str
+        where
+            L: $crate::fmt::locale::Locale + ?Sized,
+        {
+            locale.quantity_description($description).unwrap_or($description)
+        }
+
         /// Unit enum.
         #[allow(non_camel_case_types)]
         #[non_exhaustive]
         #[allow(clippy::manual_non_exhaustive)]
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub enum Units {
             $(#[allow(clippy::empty_docs)] // macros cannot expand to enum variants
             #[doc=$plural]
@@ -167,6 +216,20 @@ macro_rules! quantity {
         }
 
         impl Units {
+            /// All units of the quantity in the order they are defined.
+            pub const ALL: &'static [Units] = &[
+                $(Units::$unit,)+
+            ];
+
+            /// Unit identifier, e.g. `"kilometer"` for `Units::kilometer`.
+            #[must_use = "method returns a static value"]
+            #[allow(dead_code)]
+            pub fn identifier(&self) -> &'static str {
+                match self {
+                    $(Units::$unit => stringify!($unit),)+
+                }
+            }
+
             /// Unit abbreviation.
             #[must_use = "method returns a static value"]
             #[allow(dead_code)]
@@ -193,18 +256,350 @@ macro_rules! quantity {
                     $(Units::$unit => $plural,)+
                 }
             }
+
+            /// Unit siunitx markup, if specified.
+            #[must_use = "method returns a static value"]
+            #[allow(dead_code)]
+            pub fn markup(&self) -> Option<&'static str> {
+                match self {
+                    $(Units::$unit => None $(.or(Some($markup)))?,)+
+                }
+            }
+
+            /// Unit conversion coefficient to the base unit of the quantity as an `f64`.
+            #[doc(hidden)]
+            #[must_use = "method returns a static value"]
+            #[allow(dead_code)]
+            #[allow(clippy::eq_op)]
+            #[allow(clippy::approx_constant)]
+            pub fn coefficient_f64(&self) -> f64 {
+                match self {
+                    $(Units::$unit => $coefficient,)+
+                }
+            }
+
+            /// Unit conversion constant to the base unit of the quantity as an `f64`.
+            #[doc(hidden)]
+            #[must_use = "method returns a static value"]
+            #[allow(dead_code)]
+            #[allow(clippy::eq_op)]
+            #[allow(clippy::approx_constant)]
+            pub fn constant_f64(&self) -> f64 {
+                match self {
+                    $(Units::$unit => 0.0 $(+ $constant)?,)+
+                }
+            }
+        }
+
+        impl Units {
+            /// Coefficient to convert the unit to the base unit of the quantity for the given
+            /// underlying storage type.
+            ///
+            /// ## Generic Parameters
+            /// * `V`: Underlying storage type.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub fn coefficient<V>(&self) -> V::T
+            where
+                V: $crate::Conversion<V>,
+                Self: __system::RuntimeUnit<V>,
+            {
+                __system::RuntimeUnit::<V>::coefficient(self)
+            }
+
+            /// Constant to add to a value when converting the unit to the base unit of the
+            /// quantity for the given underlying storage type, e.g. 273.15 for `degree_celsius`.
+            ///
+            /// ## Generic Parameters
+            /// * `V`: Underlying storage type.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub fn constant<V>(&self, op: $crate::ConstantOp) -> V::T
+            where
+                V: $crate::Conversion<V>,
+                Self: __system::RuntimeUnit<V>,
+            {
+                __system::RuntimeUnit::<V>::constant(self, op)
+            }
         }
 
-        static ALL_UNITS: &[Units] = &[
-            $(Units::$unit,)+
-        ];
+        impl Units {
+            /// Returns `true` if the unit abbreviation is a prefixed abbreviation of another unit
+            /// of the quantity, e.g. `km`, or the unit is prefixed in both abbreviation and
+            /// singular description, e.g. `kg` and `kilogram`.
+            #[doc(hidden)]
+            #[must_use]
+            #[allow(dead_code)]
+            pub fn is_prefixed(&self) -> bool {
+                let abbreviation = self.abbreviation();
+
+                $crate::str::PREFIXES.iter().any(|prefix| {
+                    abbreviation
+                        .strip_prefix(prefix.symbol)
+                        .filter(|abbreviation| !abbreviation.is_empty())
+                        .map_or(false, |abbreviation| {
+                            self.singular().starts_with(prefix.name)
+                                || units().any(|unit| unit.abbreviation() == abbreviation)
+                        })
+                })
+            }
+        }
+
+        /// Returns `true` if the quantity defines units with binary prefixes, e.g. `kibibyte`.
+        #[doc(hidden)]
+        #[must_use]
+        #[allow(dead_code)]
+        pub fn binary_prefixes() -> bool {
+            units().any(|unit| {
+                $crate::str::PREFIXES.iter().any(|prefix| {
+                    prefix.binary && unit.singular().starts_with(prefix.name)
+                })
+            })
+        }
 
         /// Iterate over all defined units for this quantity.
         #[allow(dead_code)]
         pub fn units() -> impl Iterator<Item = Units> {
-            ALL_UNITS.iter().copied()
+            Units::ALL.iter().copied()
+        }
+
+        impl $crate::lib::fmt::Display for Units {
+            /// Formats the unit abbreviation.
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter<'_>) -> $crate::lib::fmt::Result {
+                f.pad(self.abbreviation())
+            }
+        }
+
+        serde! {
+        impl $crate::serde::Serialize for Units {
+            /// Serializes the unit identifier, e.g. `"kilometer"`. See
+            /// [`serde_abbreviation`]($crate::str::serde_abbreviation) to serialize the
+            /// abbreviation instead.
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                serializer.serialize_str(self.identifier())
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for Units {
+            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+            where
+                De: $crate::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> $crate::serde::de::Visitor<'de> for Visitor {
+                    type Value = Units;
+
+                    fn expecting(
+                        &self,
+                        f: &mut $crate::lib::fmt::Formatter<'_>,
+                    ) -> $crate::lib::fmt::Result {
+                        write!(f, "a unit identifier of {}", description())
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: $crate::serde::de::Error,
+                    {
+                        units().find(|unit| unit.identifier() == v)
+                            .ok_or_else(|| E::unknown_variant(v, &[$(stringify!($unit)),+]))
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }}
+
+        serde! {
+        /// Returns the coherent unit of the quantity, e.g. meter, or the first unit of the quantity
+        /// when there isn't one.
+        #[allow(dead_code)]
+        fn serde_unit() -> Units {
+            units()
+                .find(|unit| unit.coefficient_f64() == 1.0 && unit.constant_f64() == 0.0)
+                .unwrap_or(Units::ALL[0])
+        }
+
+        /// Serialize and deserialize the quantity with its unit as `{"value": 5.0, "unit": "km"}`.
+        /// Use with `#[serde(with = "...")]`. See
+        /// [`serde_quantity`]($crate::str::serde_quantity).
+        #[allow(dead_code)]
+        pub mod serde_object {
+            /// Serializes `quantity` in the coherent unit of the quantity.
+            ///
+            /// # Errors
+            /// Returns any error produced by `serializer`.
+            pub fn serialize<U, V, S>(
+                quantity: &super::$quantity<U, V>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                U: super::__system::Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Serialize,
+                super::Units: super::RuntimeUnit<V>,
+                S: $crate::serde::Serializer,
+            {
+                let unit = super::serde_unit();
+
+                $crate::str::serde_quantity::serialize_object(
+                    &quantity.get_in(unit), unit.abbreviation(), serializer)
+            }
+
+            /// Serializes `quantity` in the unit `N`.
+            ///
+            /// # Errors
+            /// Returns any error produced by `serializer`.
+            pub fn serialize_in<N, U, V, S>(
+                quantity: &super::$quantity<U, V>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                N: super::Unit + $crate::Conversion<V, T = V::T>,
+                U: super::__system::Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Serialize,
+                S: $crate::serde::Serializer,
+            {
+                $crate::str::serde_quantity::serialize_object(
+                    &quantity.get::<N>(), N::abbreviation(), serializer)
+            }
+
+            schemars! {
+            /// Generates the JSON Schema of the quantity as serialized by
+            /// [`serialize`](self::serialize): an object with a numeric value and a unit
+            /// abbreviation. Use with `#[schemars(schema_with = "...")]`.
+            #[must_use]
+            pub fn json_schema(
+                _generator: &mut $crate::schemars::SchemaGenerator,
+            ) -> $crate::schemars::Schema {
+                $crate::str::serde_quantity::object_schema(super::description(),
+                    &[$($abbreviation),+])
+            }}
+
+            /// Deserializes a quantity with its unit or a bare number in base units.
+            ///
+            /// # Errors
+            /// Returns an error when the input isn't the quantity or any error produced by
+            /// `deserializer`.
+            pub fn deserialize<'de, U, V, De>(
+                deserializer: De,
+            ) -> Result<super::$quantity<U, V>, De::Error>
+            where
+                super::$quantity<U, V>: $crate::str::serde_quantity::FromValueUnit<Value = V>
+                    + $crate::lib::str::FromStr<Err = $crate::str::ParseQuantityError>
+                    + $crate::serde::Deserialize<'de>,
+                U: super::__system::Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Deserialize<'de>,
+                De: $crate::serde::Deserializer<'de>,
+            {
+                $crate::str::serde_quantity::deserialize(deserializer, false)
+            }
+
+            /// Serializes like [`serde_object`](super::serde_object) and deserializes only a
+            /// quantity with its unit, rejecting bare numbers.
+            pub mod strict {
+                #[allow(unused_imports)]
+                pub use super::{serialize, serialize_in};
+                schemars! {
+                #[allow(unused_imports)]
+                pub use super::json_schema;}
+
+                /// Deserializes a quantity with its unit.
+                ///
+                /// # Errors
+                /// Returns an error when the input isn't the quantity with its unit or any error
+                /// produced by `deserializer`.
+                pub fn deserialize<'de, U, V, De>(
+                    deserializer: De,
+                ) -> Result<super::super::$quantity<U, V>, De::Error>
+                where
+                    super::super::$quantity<U, V>:
+                        $crate::str::serde_quantity::FromValueUnit<Value = V>
+                        + $crate::lib::str::FromStr<Err = $crate::str::ParseQuantityError>
+                        + $crate::serde::Deserialize<'de>,
+                    U: super::super::__system::Units<V> + ?Sized,
+                    V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Deserialize<'de>,
+                    De: $crate::serde::Deserializer<'de>,
+                {
+                    $crate::str::serde_quantity::deserialize(deserializer, true)
+                }
+            }
         }
 
+        /// Serialize and deserialize the quantity with its unit as `"5 km"`. Use with
+        /// `#[serde(with = "...")]`. See [`serde_quantity`]($crate::str::serde_quantity).
+        #[allow(dead_code)]
+        pub mod serde_string {
+            #[allow(unused_imports)]
+            pub use super::serde_object::deserialize;
+
+            /// Serializes `quantity` in the coherent unit of the quantity.
+            ///
+            /// # Errors
+            /// Returns any error produced by `serializer`.
+            pub fn serialize<U, V, S>(
+                quantity: &super::$quantity<U, V>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                U: super::__system::Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::fmt::Display,
+                super::Units: super::RuntimeUnit<V>,
+                S: $crate::serde::Serializer,
+            {
+                let unit = super::serde_unit();
+
+                $crate::str::serde_quantity::serialize_string(
+                    &quantity.get_in(unit), unit.abbreviation(), serializer)
+            }
+
+            /// Serializes `quantity` in the unit `N`.
+            ///
+            /// # Errors
+            /// Returns any error produced by `serializer`.
+            pub fn serialize_in<N, U, V, S>(
+                quantity: &super::$quantity<U, V>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                N: super::Unit + $crate::Conversion<V, T = V::T>,
+                U: super::__system::Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::fmt::Display,
+                S: $crate::serde::Serializer,
+            {
+                $crate::str::serde_quantity::serialize_string(
+                    &quantity.get::<N>(), N::abbreviation(), serializer)
+            }
+
+            schemars! {
+            /// Generates the JSON Schema of the quantity as serialized by
+            /// [`serialize`](self::serialize): a string with a value and a unit abbreviation.
+            /// Use with `#[schemars(schema_with = "...")]`.
+            #[must_use]
+            pub fn json_schema(
+                _generator: &mut $crate::schemars::SchemaGenerator,
+            ) -> $crate::schemars::Schema {
+                $crate::str::serde_quantity::string_schema(super::description(),
+                    &[$($abbreviation),+])
+            }}
+
+            /// Serializes like [`serde_string`](super::serde_string) and deserializes only a
+            /// quantity with its unit, rejecting bare numbers.
+            pub mod strict {
+                #[allow(unused_imports)]
+                pub use super::super::serde_object::strict::deserialize;
+                #[allow(unused_imports)]
+                pub use super::{serialize, serialize_in};
+                schemars! {
+                #[allow(unused_imports)]
+                pub use super::json_schema;}
+            }
+        }}
+
         impl<U, V> $quantity<U, V>
         where
             U: __system::Units<V> + ?Sized,
@@ -227,6 +622,34 @@ macro_rules! quantity {
                 }
             }
 
+            /// Create a new quantity from the given value and a measurement unit selected at
+            /// runtime.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::{meter, Units};
+            /// let l = Length::new_in(Units::kilometer, 1.5);
+            ///
+            /// assert_eq!(1_500.0, l.get::<meter>());
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit type, [`Units`] or [`CustomUnit`]($crate::registry::CustomUnit).
+            #[must_use = "method produces a new value"]
+            #[inline(always)]
+            pub fn new_in<N>(unit: N, v: V) -> Self
+            where
+                N: RuntimeUnit<V>,
+            {
+                $quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: __system::to_base_factor::<Dimension, U, V>(
+                        v.conversion() + unit.constant($crate::ConstantOp::Add),
+                        unit.coefficient()),
+                }
+            }
+
             /// Retrieve the value of the quantity in the given measurement unit.
             ///
             /// ## Generic Parameters
@@ -240,6 +663,168 @@ macro_rules! quantity {
                 __system::from_base::<Dimension, U, V, N>(&self.value)
             }
 
+            /// Create a new quantity from the given value and measurement unit using checked
+            /// arithmetic. Returns `None` if applying the unit's conversion factor overflows or the
+            /// converted value is not exactly representable by the underlying storage type, e.g.
+            /// `1 cm` as an integer number of meters.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[must_use = "method produces a new value"]
+            #[inline(always)]
+            pub fn checked_new<N>(v: V) -> Option<Self>
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V::T: $crate::lib::clone::Clone + $crate::num_traits::CheckedAdd
+                    + $crate::num_traits::CheckedMul + $crate::num_traits::CheckedDiv,
+            {
+                Self::try_new::<N>(v).ok()
+            }
+
+            /// Retrieve the value of the quantity in the given measurement unit using checked
+            /// arithmetic. Returns `None` if applying the unit's conversion factor overflows or
+            /// the converted value is not exactly representable by the underlying storage type.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_get<N>(&self) -> Option<V>
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V::T: $crate::lib::clone::Clone + $crate::num_traits::CheckedSub
+                    + $crate::num_traits::CheckedMul + $crate::num_traits::CheckedDiv,
+            {
+                self.try_get::<N>().ok()
+            }
+
+            /// Create a new quantity from the given value and measurement unit. Returns an error if
+            /// applying the unit's conversion factor overflows or the converted value is not
+            /// exactly representable by the underlying storage type. The error holds the exact
+            /// remainder, in base units, lost by truncation.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[inline(always)]
+            pub fn try_new<N>(v: V) -> Result<Self, $crate::ConversionError<V::T>>
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V::T: $crate::lib::clone::Clone + $crate::num_traits::CheckedAdd
+                    + $crate::num_traits::CheckedMul + $crate::num_traits::CheckedDiv,
+            {
+                use $crate::num::Zero;
+
+                let value = __system::to_base_checked::<Dimension, U, V, N>(&v)
+                    .ok_or($crate::ConversionError::Overflow)?;
+                let (value, remainder) = __system::split_value::<V>(value);
+
+                if !remainder.is_zero() {
+                    return Err($crate::ConversionError::Inexact { remainder });
+                }
+
+                Ok($quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value,
+                })
+            }
+
+            /// Retrieve the value of the quantity in the given measurement unit. Returns an error
+            /// if applying the unit's conversion factor overflows or the converted value is not
+            /// exactly representable by the underlying storage type. The error holds the exact
+            /// remainder, in the given unit, lost by truncation.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[inline(always)]
+            pub fn try_get<N>(&self) -> Result<V, $crate::ConversionError<V::T>>
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V::T: $crate::lib::clone::Clone + $crate::num_traits::CheckedSub
+                    + $crate::num_traits::CheckedMul + $crate::num_traits::CheckedDiv,
+            {
+                use $crate::num::Zero;
+
+                let value = __system::from_base_checked::<Dimension, U, V, N>(&self.value)
+                    .ok_or($crate::ConversionError::Overflow)?;
+                let (value, remainder) = __system::split_value::<V>(value);
+
+                if !remainder.is_zero() {
+                    return Err($crate::ConversionError::Inexact { remainder });
+                }
+
+                Ok(value)
+            }
+
+            /// Create a new quantity from the given value and measurement unit, rounding the
+            /// converted value with the given rounding mode when it isn't exactly representable
+            /// by the underlying storage type.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[must_use = "method produces a new value"]
+            #[inline(always)]
+            pub fn new_rounded<N>(v: V, mode: $crate::RoundingMode) -> Self
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V: $crate::lib::clone::Clone + $crate::num_traits::CheckedAdd
+                    + $crate::num_traits::CheckedSub,
+                V::T: $crate::lib::clone::Clone,
+            {
+                $quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: __system::round_value::<V>(
+                        __system::to_base_conversion::<Dimension, U, V>(
+                            v.conversion() + N::constant($crate::ConstantOp::Add),
+                            N::coefficient()),
+                        mode),
+                }
+            }
+
+            /// Retrieve the value of the quantity in the given measurement unit, rounding the
+            /// converted value with the given rounding mode when it isn't exactly representable
+            /// by the underlying storage type.
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn get_rounded<N>(&self, mode: $crate::RoundingMode) -> V
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+                V: $crate::lib::clone::Clone + $crate::num_traits::CheckedAdd
+                    + $crate::num_traits::CheckedSub,
+                V::T: $crate::lib::clone::Clone,
+            {
+                __system::round_value::<V>(
+                    __system::from_base_conversion::<Dimension, U, V>(&self.value,
+                        N::coefficient(), N::constant($crate::ConstantOp::Sub)),
+                    mode)
+            }
+
+            /// Retrieve the value of the quantity in a measurement unit selected at runtime.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::{meter, Units};
+            /// let l = Length::new::<meter>(1_500.0);
+            ///
+            /// assert_eq!(1.5, l.get_in(Units::kilometer));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit type, [`Units`] or [`CustomUnit`]($crate::registry::CustomUnit).
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn get_in<N>(&self, unit: N) -> V
+            where
+                N: RuntimeUnit<V>,
+            {
+                __system::from_base_factor::<Dimension, U, V>(&self.value, unit.coefficient(),
+                    unit.constant($crate::ConstantOp::Sub))
+            }
+
             /// Returns the largest integer less than or equal to a number in the given
             /// measurement unit.
             ///
@@ -396,6 +981,173 @@ macro_rules! quantity {
                     quantity: self,
                 }
             }
+
+            /// Creates a struct that formats `self` for display in a unit selected at runtime.
+            ///
+            /// # Examples
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::time::{picosecond, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// let t = Time::new::<picosecond>(1.0_E-1);
+            /// let a = t.into_format_args_in(Units::femtosecond, Description);
+            ///
+            /// assert_eq!("100 femtoseconds", format!("{}", a));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Unit type, [`Units`] or [`CustomUnit`]($crate::registry::CustomUnit).
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_format_args_in<N>(
+                self,
+                unit: N,
+                style: $crate::fmt::DisplayStyle
+            ) -> __system::fmt::UnitsArguments<Dimension, U, V, N>
+            where
+                N: RuntimeUnit<V>,
+            {
+                __system::fmt::UnitsArguments {
+                    quantity: self,
+                    unit,
+                    style,
+                }
+            }
+
+            /// Creates a struct that formats `self` for display as a sum of the given units, e.g.
+            /// "5 ft 3 in".
+            ///
+            /// # Notes
+            /// Units must be ordered from largest to smallest. Every unit but the last is
+            /// displayed with an integer value. The last unit is displayed with the remainder
+            /// rounded to the precision of the formatter, e.g. `{:.1}`, or to an integer when no
+            /// precision is given, as specified by `rounding`. Rounding carries into larger units.
+            ///
+            /// # Examples
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::time::{hour, minute, second, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// # use uom::RoundingMode;
+            /// let t = Time::new::<second>(5_412.0);
+            /// let units = [Units::hour, Units::minute, Units::second];
+            ///
+            /// assert_eq!("1 h 30 min 12 s",
+            ///     format!("{}", t.into_sum_format_args(&units, Abbreviation, RoundingMode::HalfEven)));
+            /// assert_eq!("1 hour 30 minutes 12 seconds",
+            ///     format!("{}", t.into_sum_format_args(&units, Description, RoundingMode::HalfEven)));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_sum_format_args(
+                self,
+                units: &[Units],
+                style: $crate::fmt::DisplayStyle,
+                rounding: $crate::RoundingMode,
+            ) -> __system::fmt::SumArguments<'_, Dimension, U, V, Units>
+            where
+                V: $crate::num::Float,
+            {
+                __system::fmt::SumArguments {
+                    quantity: self,
+                    units,
+                    style,
+                    rounding,
+                }
+            }
+
+            /// Creates a struct that formats `self` for display in the unit with an automatically
+            /// selected SI prefix, e.g. "3 mW" or "3 GW".
+            ///
+            /// # Notes
+            /// `unit` is the unprefixed unit, e.g. `watt`, or `gram` for mass. The unit with the
+            /// largest prefix, as allowed by `prefixes`, that displays a value greater than or
+            /// equal to one after rounding to the precision of the formatter is used. Values
+            /// smaller than one in every unit are displayed in the unit with the smallest prefix.
+            /// Zero and non-finite values are displayed in `unit`.
+            ///
+            /// # Examples
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::power::{milliwatt, watt, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// # use uom::fmt::Prefixes;
+            /// let p = Power::new::<milliwatt>(3.0);
+            ///
+            /// assert_eq!("3.0 mW",
+            ///     format!("{:.1}", p.into_prefixed_format_args(Units::watt, Prefixes::Engineering,
+            ///         Abbreviation)));
+            /// assert_eq!("3.0 milliwatts",
+            ///     format!("{:.1}", p.into_prefixed_format_args(Units::watt, Prefixes::Engineering,
+            ///         Description)));
+            ///
+            /// let p = Power::new::<watt>(1_500.0);
+            /// let units = [Units::watt, Units::megawatt];
+            ///
+            /// assert_eq!("1500 W",
+            ///     format!("{}", p.into_prefixed_format_args(Units::watt, Prefixes::Only(&units),
+            ///         Abbreviation)));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_prefixed_format_args(
+                self,
+                unit: Units,
+                prefixes: $crate::fmt::Prefixes<'_, Units>,
+                style: $crate::fmt::DisplayStyle,
+            ) -> __system::fmt::PrefixedArguments<'_, Dimension, U, V, Units>
+            where
+                V: $crate::num::Float,
+            {
+                __system::fmt::PrefixedArguments {
+                    quantity: self,
+                    unit,
+                    prefixes,
+                    style,
+                }
+            }
+        }
+
+        impl<U, V> $quantity<U, V>
+        where
+            U: __system::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Creates a struct that formats `self` for display in the given unit with a localized
+            /// number format and unit name, e.g. "1.500,5 Meter".
+            ///
+            /// # Notes
+            /// The value is displayed using the precision of the formatter with the locale's
+            /// decimal and grouping separators. The unit name is selected by the locale's plural
+            /// category of the displayed value. The English description of the unit is used when
+            /// the locale has no name for the unit.
+            ///
+            /// # Examples
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::time::{hour, Units};
+            /// # use uom::fmt::locale::{ENGLISH, GERMAN, JAPANESE};
+            /// let t = Time::new::<hour>(1.0);
+            ///
+            /// assert_eq!("1 hour",
+            ///     format!("{}", t.into_localized_format_args(Units::hour, &ENGLISH)));
+            /// assert_eq!("1,0 Stunden",
+            ///     format!("{:.1}", t.into_localized_format_args(Units::hour, &GERMAN)));
+            /// assert_eq!("3,600秒",
+            ///     format!("{}", t.into_localized_format_args(Units::second, &JAPANESE)));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_localized_format_args<L>(
+                self,
+                unit: Units,
+                locale: &L,
+            ) -> __system::fmt::LocalizedArguments<'_, Dimension, U, V, Units, L>
+            where
+                L: $crate::fmt::locale::Locale + ?Sized,
+            {
+                __system::fmt::LocalizedArguments {
+                    quantity: self,
+                    unit,
+                    locale,
+                }
+            }
         }
 
         impl<N> __system::fmt::Arguments<Dimension, N>
@@ -424,28 +1176,508 @@ macro_rules! quantity {
         }
 
         mod str {
+            /// Calls `f` with the abbreviation, singular, and plural description of every unit.
+            pub(super) fn unit_names(f: &mut dyn FnMut(&'static str)) {
+                for unit in super::units() {
+                    f(unit.abbreviation());
+                    f(unit.singular());
+                    f(unit.plural());
+                }
+            }
+
+            /// Creates an error for the unrecognized unit `unit`, a sub-slice of `s`.
+            fn unknown_unit(s: &str, unit: &str) -> $crate::str::ParseQuantityError {
+                let start = unit.as_ptr() as usize - s.as_ptr() as usize;
+                let other_quantity = super::__system::unit_quantity(unit)
+                    .filter(|&quantity| quantity != super::description());
+
+                $crate::str::ParseQuantityError::unknown_unit(
+                    super::description(),
+                    start..start + unit.len(),
+                    unit,
+                    unit_names,
+                    other_quantity)
+            }
+
+            impl $crate::lib::str::FromStr for super::Units {
+                type Err = $crate::str::ParseQuantityError;
+
+                /// Parses a unit by abbreviation, singular, or plural description.
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    #[allow(unreachable_patterns)]
+                    match s {
+                        $($abbreviation | $singular | $plural => Ok(super::Units::$unit),)+
+                        _ => Err(unknown_unit(s, s)),
+                    }
+                }
+            }
+
             storage_types! {
                 use $crate::lib::str::FromStr;
-                use $crate::str::ParseQuantityError::*;
+                use $crate::registry::CustomUnit;
+                use $crate::str::ParseQuantityError;
 
                 impl<U> FromStr for super::super::$quantity<U, V>
                 where
                     U: super::super::__system::Units<V> + ?Sized,
                 {
-                    type Err = $crate::str::ParseQuantityError;
+                    type Err = ParseQuantityError;
 
                     fn from_str(s: &str) -> Result<Self, Self::Err> {
-                        let mut parts = s.splitn(2, ' ');
-                        let value = parts.next().unwrap();
-                        let unit = parts.next().ok_or(NoSeparator)?;
-                        let value = value.parse::<V>().map_err(|_| ValueParseError)?;
-
-                        #[allow(unreachable_patterns)]
-                        match unit.trim() {
-                            $($abbreviation | $singular | $plural => Ok(Self::new::<super::super::$unit>(value)),)+
-                            _ => Err(UnknownUnit),
+                        Self::from_str_with(s, $crate::str::ParseOptions::new())
+                    }
+                }
+
+                impl<U> super::super::$quantity<U, V>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    /// Parses a string into a quantity using the given options. See
+                    /// [`ParseOptions`]($crate::str::ParseOptions).
+                    ///
+                    /// # Errors
+                    /// Returns a [`ParseQuantityError`]($crate::str::ParseQuantityError) when the
+                    /// string can't be parsed.
+                    pub fn from_str_with(
+                        s: &str,
+                        options: $crate::str::ParseOptions,
+                    ) -> Result<Self, ParseQuantityError> {
+                        Self::parse(s, options, &|_| None)
+                    }
+
+                    /// Parses a string into a quantity using the given options. Units registered
+                    /// in `registry` are recognized in addition to the units of the quantity. See
+                    /// [`from_str_with`](#method.from_str_with).
+                    ///
+                    /// # Errors
+                    /// Returns a [`ParseQuantityError`]($crate::str::ParseQuantityError) when the
+                    /// string can't be parsed.
+                    pub fn from_str_with_registry<'a, S>(
+                        s: &str,
+                        options: $crate::str::ParseOptions,
+                        registry: &$crate::registry::UnitRegistry<
+                            'a, super::super::Dimension, V, S>,
+                    ) -> Result<Self, ParseQuantityError>
+                    where
+                        S: AsRef<[Option<CustomUnit<'a, super::super::Dimension, V>>]>,
+                    {
+                        Self::parse(s, options, &|unit| registry.find(unit))
+                    }
+
+                    /// Parses a string into a quantity. `find` looks up custom units by name.
+                    fn parse<'a>(
+                        s: &str,
+                        options: $crate::str::ParseOptions,
+                        find: &dyn Fn(&str) -> Option<CustomUnit<'a, super::super::Dimension, V>>,
+                    ) -> Result<Self, ParseQuantityError> {
+                        $crate::str::parse_layouts(
+                            s,
+                            options,
+                            super::super::description(),
+                            &mut |value, unit| Ok(match find(unit) {
+                                Some(unit) => Some(Self::new_in(unit, value)),
+                                None => from_unit(value, unit, options),
+                            }),
+                            &|unit| super::unknown_unit(s, unit))
+                    }
+                }
+
+                impl<U> super::super::$quantity<U, V>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    /// Parses a sum of quantities, e.g. `5 ft 3 in` or `1 h 30 min 12 s`. When the
+                    /// first value is negative the following values are subtracted, e.g. `-1 h 30
+                    /// min` is -1.5 hours.
+                    ///
+                    /// # Errors
+                    /// Returns a [`ParseQuantityError`]($crate::str::ParseQuantityError) when the
+                    /// string can't be parsed.
+                    pub fn from_str_sum(s: &str) -> Result<Self, ParseQuantityError> {
+                        Self::from_str_sum_with(s, $crate::str::ParseOptions::new())
+                    }
+
+                    /// Parses a sum of quantities using the given options. Only
+                    /// [`ParseOptions::prefixes`]($crate::str::ParseOptions::prefixes) is used.
+                    /// See [`from_str_sum`](#method.from_str_sum).
+                    ///
+                    /// # Errors
+                    /// Returns a [`ParseQuantityError`]($crate::str::ParseQuantityError) when the
+                    /// string can't be parsed.
+                    pub fn from_str_sum_with(
+                        s: &str,
+                        options: $crate::str::ParseOptions,
+                    ) -> Result<Self, ParseQuantityError> {
+                        let mut sum: Option<(Self, bool)> = None;
+
+                        for term in $crate::str::terms(s) {
+                            let (value, unit) = term.map_err(|span| {
+                                ParseQuantityError::no_separator(super::super::description(), span)
+                            })?;
+                            let negative = value.starts_with('-');
+                            let value = value.parse::<V>().map_err(|e| {
+                                ParseQuantityError::value_parse_error(
+                                    super::super::description(), $crate::str::span(s, value), &e)
+                            })?;
+                            let quantity = from_unit(value, unit, options)
+                                .ok_or_else(|| super::unknown_unit(s, unit))?;
+
+                            // Sum base values directly as not all kinds support addition.
+                            sum = Some(match sum {
+                                None => (quantity, negative),
+                                Some((mut sum, negative)) => {
+                                    sum.value = if negative {
+                                        sum.value - quantity.value
+                                    } else {
+                                        sum.value + quantity.value
+                                    };
+
+                                    (sum, negative)
+                                }
+                            });
                         }
+
+                        sum.map(|(sum, _)| sum).ok_or_else(|| {
+                            ParseQuantityError::no_separator(
+                                super::super::description(), 0..s.len())
+                        })
+                    }
+                }
+
+                serde! {
+                impl<U> $crate::str::serde_quantity::FromValueUnit for super::super::$quantity<U, V>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    type Value = V;
+
+                    schemars! {
+                    const DESCRIPTION: &'static str = super::super::description();
+                    const ABBREVIATIONS: &'static [&'static str] = &[$($abbreviation),+];}
+
+                    fn from_value_unit(value: V, unit: &str) -> Result<Self, ParseQuantityError> {
+                        from_unit(value, unit, $crate::str::ParseOptions::new())
+                            .ok_or_else(|| super::unknown_unit(unit, unit))
+                    }
+                }}
+
+                /// Creates a quantity from the given value and unit. Returns `None` if the unit
+                /// isn't recognized.
+                fn from_unit<U>(
+                    value: V,
+                    unit: &str,
+                    options: $crate::str::ParseOptions,
+                ) -> Option<super::super::$quantity<U, V>>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    use $crate::Conversion;
+
+                    #[allow(unreachable_patterns)]
+                    match unit {
+                        $($abbreviation | $singular | $plural => {
+                            Some(super::super::$quantity::new::<super::super::$unit>(value))
+                        })+
+                        unit => {
+                            let coefficient = $crate::str::UnitExpression::parse(unit)
+                                .and_then(|expression| {
+                                    super::super::__system::unit_expression_factor::<
+                                        super::super::Dimension, V>(&expression, options.prefixes)
+                                })?;
+
+                            Some(super::super::$quantity {
+                                dimension: $crate::lib::marker::PhantomData,
+                                units: $crate::lib::marker::PhantomData,
+                                value: super::super::__system::to_base_factor::<
+                                    super::super::Dimension, U, V>(value.conversion(), coefficient),
+                            })
+                        }
+                    }
+                }
+            }
+        }
+
+        mod runtime {
+            storage_types! {
+                use $crate::registry::CustomUnit;
+                use $crate::RuntimeConversion;
+                use super::super::{Dimension, Units};
+
+                impl super::super::RuntimeUnit<V> for Units {}
+
+                impl super::super::__system::RuntimeUnit<V> for Units {
+                    #[inline(always)]
+                    fn abbreviation(&self) -> &str {
+                        Units::abbreviation(self)
+                    }
+
+                    #[inline(always)]
+                    fn singular(&self) -> &str {
+                        Units::singular(self)
+                    }
+
+                    #[inline(always)]
+                    fn plural(&self) -> &str {
+                        Units::plural(self)
+                    }
+
+                    #[inline(always)]
+                    fn markup(&self) -> Option<&str> {
+                        Units::markup(self)
+                    }
+
+                    #[inline(always)]
+                    fn coefficient(&self) -> <V as $crate::Conversion<V>>::T {
+                        match self {
+                            $(Units::$unit => {
+                                <super::super::$unit as $crate::Conversion<V>>::coefficient()
+                            })+
+                        }
+                    }
+
+                    #[inline(always)]
+                    fn constant(&self, op: $crate::ConstantOp) -> <V as $crate::Conversion<V>>::T {
+                        match self {
+                            $(Units::$unit => {
+                                <super::super::$unit as $crate::Conversion<V>>::constant(op)
+                            })+
+                        }
+                    }
+                }
+
+                impl super::super::RuntimeUnit<V> for CustomUnit<'_, Dimension, V> {}
+
+                impl super::super::__system::RuntimeUnit<V> for CustomUnit<'_, Dimension, V> {
+                    #[inline(always)]
+                    fn abbreviation(&self) -> &str {
+                        CustomUnit::abbreviation(self)
+                    }
+
+                    #[inline(always)]
+                    fn singular(&self) -> &str {
+                        CustomUnit::singular(self)
+                    }
+
+                    #[inline(always)]
+                    fn plural(&self) -> &str {
+                        CustomUnit::plural(self)
+                    }
+
+                    #[inline(always)]
+                    fn markup(&self) -> Option<&str> {
+                        None
                     }
+
+                    #[inline(always)]
+                    fn coefficient(&self) -> <V as $crate::Conversion<V>>::T {
+                        // Representable, checked by `CustomUnit::new`.
+                        V::factor_from_f64(CustomUnit::coefficient(self))
+                            .expect("coefficient can't be represented by the storage type")
+                    }
+
+                    #[inline(always)]
+                    fn constant(&self, op: $crate::ConstantOp) -> <V as $crate::Conversion<V>>::T {
+                        // Match the sign of the zero constant of compiled units.
+                        let constant = match (CustomUnit::constant(self), op) {
+                            (c, $crate::ConstantOp::Add) if c == 0.0 => -0.0,
+                            (c, _) => c,
+                        };
+
+                        // Representable, checked by `CustomUnit::with_constant`.
+                        V::factor_from_f64(constant)
+                            .expect("constant can't be represented by the storage type")
+                    }
+                }
+            }
+        }
+
+        mod localized {
+            storage_types! {
+                use $crate::lib::fmt;
+                use super::super::Units;
+
+                impl<'a, U, L> fmt::Display
+                    for super::super::__system::fmt::LocalizedArguments<
+                        'a, super::super::Dimension, U, V, Units, L>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                    L: $crate::fmt::locale::Locale + ?Sized,
+                {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        let value = self.quantity.get_in(self.unit);
+
+                        $crate::fmt::locale::write_localized(f, self.locale, &value, $description,
+                            self.unit.singular(), self.unit.plural())
+                    }
+                }
+            }
+        }
+
+        mod display {
+            storage_types! {
+                types: Float;
+
+                use $crate::lib::fmt;
+                use $crate::fmt::{DisplayStyle, Prefixes};
+                use $crate::RoundingMode;
+                use $crate::num::Float;
+                use super::super::Units;
+
+                impl<'a, U> fmt::Display
+                    for super::super::__system::fmt::SumArguments<'a, super::super::Dimension, U, V, Units>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        let (last, units) = match self.units.split_last() {
+                            Some(units) => units,
+                            None => return Ok(()),
+                        };
+                        let precision = f.precision();
+                        let scale = V::powi(10.0, precision.unwrap_or(0) as i32);
+                        let round = |v: V| {
+                            let v = v * scale;
+                            let r = match self.rounding {
+                                RoundingMode::Trunc => v.trunc(),
+                                RoundingMode::Floor => v.floor(),
+                                RoundingMode::Ceil => v.ceil(),
+                                RoundingMode::HalfEven if (v.round() - v).abs() == 0.5 => {
+                                    (v / 2.0).round() * 2.0
+                                }
+                                RoundingMode::HalfEven => v.round(),
+                            };
+
+                            r / scale
+                        };
+                        let total = round(self.quantity.get_in(*last));
+                        let mut remainder = total.abs();
+
+                        if total.is_sign_negative() && remainder != 0.0 {
+                            f.write_str("-")?;
+                        }
+
+                        for &unit in units {
+                            let ratio =
+                                super::super::$quantity::<U, V>::new_in(unit, 1.0).get_in(*last);
+                            let quotient = remainder / ratio;
+                            let nearest = quotient.round();
+
+                            // Avoid a whole value of one less due to floating point error.
+                            let whole = if (quotient - nearest).abs()
+                                <= quotient.abs().max(1.0) * 16.0 * V::epsilon()
+                            {
+                                nearest
+                            } else {
+                                quotient.floor()
+                            };
+
+                            remainder = (remainder - whole * ratio).max(0.0);
+                            write_unit(f, whole, None, unit, self.style)?;
+                            f.write_str(" ")?;
+                        }
+
+                        write_unit(f, (remainder * scale).round() / scale, precision, *last,
+                            self.style)
+                    }
+                }
+
+                impl<'a, U> fmt::Display
+                    for super::super::__system::fmt::PrefixedArguments<
+                        'a, super::super::Dimension, U, V, Units>
+                where
+                    U: super::super::__system::Units<V> + ?Sized,
+                {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        let precision = f.precision();
+                        let scale = V::powi(10.0, precision.unwrap_or(0) as i32);
+                        let value = self.quantity.get_in(self.unit);
+                        let mut selected: Option<(Units, bool)> = None;
+                        let mut select = |unit: Units| {
+                            let displayed = self.quantity.get_in(unit).abs();
+                            let displayed = match precision {
+                                Some(_) => (displayed * scale).round() / scale,
+                                None => displayed,
+                            };
+                            let at_least_one = displayed >= 1.0;
+
+                            // Prefer the largest unit displaying a value of at least one, otherwise
+                            // the smallest unit.
+                            let better = match selected {
+                                None => true,
+                                Some((best, best_at_least_one)) => {
+                                    if at_least_one != best_at_least_one {
+                                        at_least_one
+                                    } else if at_least_one {
+                                        unit.coefficient_f64() > best.coefficient_f64()
+                                    } else {
+                                        unit.coefficient_f64() < best.coefficient_f64()
+                                    }
+                                }
+                            };
+
+                            if better {
+                                selected = Some((unit, at_least_one));
+                            }
+                        };
+
+                        if value != 0.0 && value.is_finite() {
+                            match self.prefixes {
+                                Prefixes::Only(units) => {
+                                    units.iter().copied().for_each(&mut select);
+                                }
+                                Prefixes::All | Prefixes::Engineering => {
+                                    let engineering =
+                                        matches!(self.prefixes, Prefixes::Engineering);
+
+                                    super::super::units()
+                                        .filter(|&u| is_prefixed_of(u, self.unit, engineering))
+                                        .for_each(&mut select);
+                                }
+                            }
+                        }
+
+                        let unit = selected.map_or(self.unit, |(unit, _)| unit);
+
+                        write_unit(f, self.quantity.get_in(unit), precision, unit,
+                            self.style)
+                    }
+                }
+
+                /// Returns `true` if `unit` is `base` or `base` with an SI prefix. Only prefixes
+                /// that are a power of 1000 are considered when `engineering` is `true`.
+                fn is_prefixed_of(unit: Units, base: Units, engineering: bool) -> bool {
+                    unit.abbreviation() == base.abbreviation()
+                        || $crate::str::PREFIXES.iter().any(|prefix| {
+                            let power_of_1000 = !matches!(prefix.name,
+                                "hecto" | "deca" | "deka" | "deci" | "centi");
+
+                            !prefix.binary
+                                && (power_of_1000 || !engineering)
+                                && unit.abbreviation().strip_prefix(prefix.symbol)
+                                    == Some(base.abbreviation())
+                                && unit.singular().strip_prefix(prefix.name)
+                                    == Some(base.singular())
+                        })
+                }
+
+                /// Writes a value and unit.
+                fn write_unit(
+                    f: &mut fmt::Formatter<'_>,
+                    value: V,
+                    precision: Option<usize>,
+                    unit: Units,
+                    style: DisplayStyle,
+                ) -> fmt::Result {
+                    $crate::fmt::write_value_start(f, style)?;
+
+                    match precision {
+                        Some(precision) => write!(f, "{:.*}", precision, value)?,
+                        None => write!(f, "{}", value)?,
+                    }
+
+                    $crate::fmt::write_unit(f, style, unit.abbreviation(),
+                        if value == 1.0 { unit.singular() } else { unit.plural() },
+                        unit.markup())
                 }
             }
         }
@@ -0,0 +1,307 @@
+//! Units of measure registered at runtime, e.g. plant-specific units loaded from a configuration
+//! file.
+//!
+//! A [`UnitRegistry`] is created for a quantity using the quantity's `registry` function and
+//! stores [`CustomUnit`]s in caller-provided storage, e.g. an array or a `Vec`. Registered units
+//! are used like compiled units with the quantity's `new_in`, `get_in`, `into_format_args_in`, and
+//! `from_str_with_registry` functions.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # use uom::si::f64::*;
+//! # use uom::si::volume::{self, cubic_meter};
+//! # use uom::fmt::DisplayStyle::*;
+//! # use uom::str::ParseOptions;
+//! use uom::registry::CustomUnit;
+//!
+//! let mut registry = volume::registry([None; 4]);
+//! registry.register(CustomUnit::new("tote", "tote", "totes", 1.04).unwrap()).unwrap();
+//!
+//! let tote = registry.find("tote").unwrap();
+//! let v = Volume::from_str_with_registry("2 totes", ParseOptions::new(), &registry).unwrap();
+//!
+//! assert_eq!(2.08, v.get::<cubic_meter>());
+//! assert_eq!(2.0, v.get_in(tote));
+//! assert_eq!("2 totes", format!("{}", v.into_format_args_in(tote, Description)));
+//! ```
+
+use crate::lib::fmt::{self, Debug, Display, Formatter};
+use crate::lib::marker::PhantomData;
+use crate::RuntimeConversion;
+
+/// Unit of measure for the quantity with dimension `D` defined at runtime.
+///
+/// ## Generic Parameters
+/// * `D`: Dimension of the quantity the unit belongs to.
+/// * `V`: Underlying storage type of the quantity the unit is used with.
+pub struct CustomUnit<'a, D: ?Sized, V> {
+    abbreviation: &'a str,
+    singular: &'a str,
+    plural: &'a str,
+    coefficient: f64,
+    constant: f64,
+    dimension: PhantomData<fn(&D, V)>,
+}
+
+impl<'a, D: ?Sized, V> CustomUnit<'a, D, V>
+where
+    V: RuntimeConversion<V>,
+{
+    /// Creates a new unit with the given abbreviation, singular and plural description, and
+    /// coefficient to convert the unit to the base unit of the quantity.
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::Invalid`] when a name is empty or the coefficient isn't finite
+    /// and positive or can't be represented by the underlying storage type.
+    pub fn new(
+        abbreviation: &'a str,
+        singular: &'a str,
+        plural: &'a str,
+        coefficient: f64,
+    ) -> Result<Self, RegistryError> {
+        if abbreviation.is_empty()
+            || singular.is_empty()
+            || plural.is_empty()
+            || !coefficient.is_finite()
+            || coefficient <= 0.0
+            || V::factor_from_f64(coefficient).is_none()
+        {
+            return Err(RegistryError::Invalid);
+        }
+
+        Ok(CustomUnit {
+            abbreviation,
+            singular,
+            plural,
+            coefficient,
+            constant: 0.0,
+            dimension: PhantomData,
+        })
+    }
+
+    /// Sets the constant to add to a value when converting the unit to the base unit of the
+    /// quantity, e.g. 273.15 for degree Celsius.
+    ///
+    /// # Errors
+    /// Returns [`RegistryError::Invalid`] when the constant isn't finite or can't be represented
+    /// by the underlying storage type.
+    pub fn with_constant(mut self, constant: f64) -> Result<Self, RegistryError> {
+        if !constant.is_finite() || V::factor_from_f64(constant).is_none() {
+            return Err(RegistryError::Invalid);
+        }
+
+        self.constant = constant;
+
+        Ok(self)
+    }
+}
+
+impl<'a, D: ?Sized, V> CustomUnit<'a, D, V> {
+    /// Unit abbreviation.
+    #[must_use]
+    pub const fn abbreviation(&self) -> &'a str {
+        self.abbreviation
+    }
+
+    /// Unit singular description.
+    #[must_use]
+    pub const fn singular(&self) -> &'a str {
+        self.singular
+    }
+
+    /// Unit plural description.
+    #[must_use]
+    pub const fn plural(&self) -> &'a str {
+        self.plural
+    }
+
+    /// Coefficient to convert the unit to the base unit of the quantity.
+    #[must_use]
+    pub const fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    /// Constant to add to a value when converting the unit to the base unit of the quantity.
+    #[must_use]
+    pub const fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Returns `true` if `name` is the abbreviation, singular, or plural description of the unit.
+    fn is_named(&self, name: &str) -> bool {
+        self.abbreviation == name || self.singular == name || self.plural == name
+    }
+}
+
+impl<D: ?Sized, V> Clone for CustomUnit<'_, D, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: ?Sized, V> Copy for CustomUnit<'_, D, V> {}
+
+impl<D: ?Sized, V> PartialEq for CustomUnit<'_, D, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.abbreviation == other.abbreviation
+            && self.singular == other.singular
+            && self.plural == other.plural
+            && self.coefficient == other.coefficient
+            && self.constant == other.constant
+    }
+}
+
+impl<D: ?Sized, V> Debug for CustomUnit<'_, D, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomUnit")
+            .field("abbreviation", &self.abbreviation)
+            .field("singular", &self.singular)
+            .field("plural", &self.plural)
+            .field("coefficient", &self.coefficient)
+            .field("constant", &self.constant)
+            .finish()
+    }
+}
+
+/// Error encountered while creating or registering a [`CustomUnit`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RegistryError {
+    /// A name of the unit matches the abbreviation, singular, or plural description of a built-in
+    /// unit of the quantity.
+    BuiltInCollision,
+
+    /// A name of the unit matches the abbreviation, singular, or plural description of an already
+    /// registered unit.
+    Collision,
+
+    /// A name of the unit is empty, the coefficient isn't finite and positive, the constant isn't
+    /// finite, or the coefficient or constant can't be represented by the underlying storage
+    /// type.
+    Invalid,
+
+    /// The registry's storage has no free slots.
+    Full,
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::BuiltInCollision => write!(f, "unit name is used by a built-in unit"),
+            RegistryError::Collision => write!(f, "unit name is used by a registered unit"),
+            RegistryError::Invalid => write!(f, "invalid unit name, coefficient, or constant"),
+            RegistryError::Full => write!(f, "unit registry is full"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::error::Error for RegistryError {}
+
+/// Registry of [`CustomUnit`]s for the quantity with dimension `D`. Created using the quantity's
+/// `registry` function.
+///
+/// Units are stored in caller-provided storage, `S`, e.g. `[Option<CustomUnit<'a, D, V>>; N]`,
+/// `&mut [Option<CustomUnit<'a, D, V>>]`, or `Vec<Option<CustomUnit<'a, D, V>>>`, where every
+/// `None` slot can hold one unit. The registry doesn't allocate and is `Send` and `Sync` when `S`
+/// is.
+///
+/// ## Generic Parameters
+/// * `D`: Dimension of the quantity units are registered for.
+/// * `V`: Underlying storage type of the quantity units are registered for.
+/// * `S`: Storage for registered units.
+pub struct UnitRegistry<'a, D: ?Sized, V, S> {
+    storage: S,
+    quantity: &'static str,
+    unit_names: fn(&mut dyn FnMut(&'static str)),
+    units: PhantomData<fn() -> CustomUnit<'a, D, V>>,
+}
+
+impl<'a, D: ?Sized, V, S> UnitRegistry<'a, D, V, S>
+where
+    S: AsRef<[Option<CustomUnit<'a, D, V>>]>,
+{
+    /// Creates a new registry. Any units already in `storage` are removed.
+    ///
+    /// * `quantity`: Quantity description.
+    /// * `unit_names`: Calls the given callback with the abbreviation, singular, and plural
+    ///   description of every built-in unit of the quantity.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn new(
+        mut storage: S,
+        quantity: &'static str,
+        unit_names: fn(&mut dyn FnMut(&'static str)),
+    ) -> Self
+    where
+        S: AsMut<[Option<CustomUnit<'a, D, V>>]>,
+    {
+        storage.as_mut().iter_mut().for_each(|slot| *slot = None);
+
+        UnitRegistry { storage, quantity, unit_names, units: PhantomData }
+    }
+
+    /// Description of the quantity units are registered for.
+    #[must_use]
+    pub fn quantity(&self) -> &'static str {
+        self.quantity
+    }
+
+    /// Registers the given unit.
+    ///
+    /// # Errors
+    /// Returns a [`RegistryError`] when a name of the unit is already used by a built-in or
+    /// registered unit, or the storage is full. Units are validated when created, see
+    /// [`CustomUnit::new`].
+    pub fn register(&mut self, unit: CustomUnit<'a, D, V>) -> Result<(), RegistryError>
+    where
+        S: AsMut<[Option<CustomUnit<'a, D, V>>]>,
+    {
+        let names = [unit.abbreviation, unit.singular, unit.plural];
+        let mut built_in = false;
+
+        (self.unit_names)(&mut |name| built_in |= names.contains(&name));
+
+        if built_in {
+            return Err(RegistryError::BuiltInCollision);
+        }
+
+        if self.units().any(|u| names.iter().any(|name| u.is_named(name))) {
+            return Err(RegistryError::Collision);
+        }
+
+        let slot = self
+            .storage
+            .as_mut()
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(RegistryError::Full)?;
+
+        *slot = Some(unit);
+
+        Ok(())
+    }
+
+    /// Finds a registered unit by abbreviation, singular, or plural description.
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<CustomUnit<'a, D, V>> {
+        self.units().find(|unit| unit.is_named(name))
+    }
+
+    /// Returns an iterator over the registered units in registration order.
+    pub fn units(&self) -> impl Iterator<Item = CustomUnit<'a, D, V>> + '_ {
+        self.storage.as_ref().iter().flatten().copied()
+    }
+}
+
+impl<D: ?Sized, V, S> Debug for UnitRegistry<'_, D, V, S>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnitRegistry")
+            .field("quantity", &self.quantity)
+            .field("storage", &self.storage)
+            .finish()
+    }
+}
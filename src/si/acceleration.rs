@@ -34,7 +34,7 @@ quantity! {
         @decameter_per_second_squared: prefix!(deca); "dam/s²", "decameter per second squared",
             "decameters per second squared";
         @meter_per_second_squared: prefix!(none); "m/s²", "meter per second squared",
-            "meters per second squared";
+            "meters per second squared", r"\meter\per\second\squared";
         @decimeter_per_second_squared: prefix!(deci); "dm/s²", "decimeter per second squared",
             "decimeters per second squared";
         @centimeter_per_second_squared: prefix!(centi); "cm/s²", "centimeter per second squared",
@@ -122,4 +122,59 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "std")]
+    mod fmt {
+        storage_types! {
+            types: Float;
+
+            use crate::fmt::DisplayStyle::*;
+            use crate::si::acceleration as a;
+            use crate::si::quantities::*;
+
+            #[test]
+            fn markup() {
+                let g = Acceleration::new::<a::meter_per_second_squared>(9.81);
+                let u = Acceleration::new::<a::micrometer_per_second_squared>(-2.0);
+
+                assert_eq!(r"\qty{9.81}{\meter\per\second\squared}",
+                    format!("{}", g.into_format_args(a::meter_per_second_squared, Siunitx)));
+                assert_eq!(r"9.81\,\mathrm{m/s^{2}}",
+                    format!("{}", g.into_format_args(a::meter_per_second_squared, Latex)));
+                assert_eq!(r"\qty{-2}{\micro\meter\per\second\squared}",
+                    format!("{}", u.into_format_args(a::micrometer_per_second_squared, Siunitx)));
+                assert_eq!(r"-2\,\mathrm{\mu{}m/s^{2}}",
+                    format!("{}", u.into_format_args(a::micrometer_per_second_squared, Latex)));
+                assert_eq!("-2&nbsp;&micro;m/s<sup>2</sup>",
+                    format!("{}", u.into_format_args(a::micrometer_per_second_squared, Html)));
+                assert_eq!(concat!("<mrow><mn>-2</mn><mo>&#x2062;</mo>",
+                        "<mi mathvariant=\"normal\">&micro;m</mi><mo>/</mo>",
+                        "<msup><mi mathvariant=\"normal\">s</mi><mn>2</mn></msup></mrow>"),
+                    format!("{}", u.into_format_args(a::micrometer_per_second_squared, MathMl)));
+            }
+
+            #[test]
+            fn siunitx_generated() {
+                use crate::si::{electrical_resistance as er, length as l, pressure as p};
+                use crate::si::velocity as v;
+
+                let kmh = Velocity::new::<v::kilometer_per_hour>(3.0);
+                let fps = Velocity::new::<v::foot_per_second>(3.0);
+                let kpa = Pressure::new::<p::kilopascal>(3.0);
+                let kohm = ElectricalResistance::new::<er::kiloohm>(3.0);
+                let uin = Length::new::<l::microinch>(3.0);
+
+                assert_eq!(r"\qty{3}{\kilo\meter\per\hour}",
+                    format!("{}", kmh.into_format_args(v::kilometer_per_hour, Siunitx)));
+                assert_eq!(r"\qty{3}{ft/s}",
+                    format!("{}", fps.into_format_args(v::foot_per_second, Siunitx)));
+                assert_eq!(r"\qty{3}{\kilo\pascal}",
+                    format!("{}", kpa.into_format_args(p::kilopascal, Siunitx)));
+                assert_eq!(r"\qty{3}{\kilo\ohm}",
+                    format!("{}", kohm.into_format_args(er::kiloohm, Siunitx)));
+                assert_eq!(r"\qty{3}{\micro in}",
+                    format!("{}", uin.into_format_args(l::microinch, Siunitx)));
+            }
+        }
+    }
 }
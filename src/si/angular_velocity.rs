@@ -51,4 +51,28 @@ mod tests {
             }
         }
     }
+
+    mod dyn_quantity {
+        storage_types! {
+            types: Float;
+
+            use crate::si::angular_velocity as v;
+            use crate::si::quantities::*;
+            use crate::si::{DimensionError, DynQuantity};
+            use crate::tests::Test;
+
+            #[test]
+            fn kind() {
+                let d: DynQuantity<V> = "2 rad/s".parse().unwrap();
+                let f = DynQuantity::from(Frequency::default());
+
+                Test::assert_eq(&2.0, &AngularVelocity::try_from(d).unwrap()
+                    .get::<v::radian_per_second>());
+                assert!(Frequency::try_from(d).is_err());
+                assert_eq!(Err(DimensionError::Kind { exponents: d.exponents() }), d + f);
+                assert!((d + DynQuantity::from(AngularVelocity::default())).is_ok());
+                assert!(Frequency::try_from(d / DynQuantity::new(1.0, [0; 7])).is_ok());
+            }
+        }
+    }
 }
@@ -78,3 +78,235 @@ quantity! {
         @deciban: 3.321_928_094_887_363_E0 * prefix!(deci) / 8.0; "deciban", "deciban", "decibans";
     }
 }
+
+/// Prefixes used to scale information when formatting, e.g. "1.5 GiB" or "1.6 GB".
+#[derive(Clone, Copy, Debug)]
+pub enum Scaling {
+    /// IEC binary prefixes that are powers of 1024, e.g. "KiB" or "GiB".
+    Binary,
+
+    /// SI decimal prefixes that are powers of 1000, e.g. "kB" or "GB".
+    Decimal,
+}
+
+/// Unit of information that is scaled when formatting.
+#[derive(Clone, Copy, Debug)]
+pub enum InformationUnit {
+    /// Bits, e.g. "Mb" or "Mb/s".
+    Bit,
+
+    /// Bytes, e.g. "MB" or "MB/s".
+    Byte,
+}
+
+#[allow(dead_code)]
+#[rustfmt::skip]
+static BINARY_BITS: &[Units] = &[
+    Units::bit, Units::kibibit, Units::mebibit, Units::gibibit, Units::tebibit, Units::pebibit,
+    Units::exbibit, Units::zebibit, Units::yobibit,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static DECIMAL_BITS: &[Units] = &[
+    Units::bit, Units::kilobit, Units::megabit, Units::gigabit, Units::terabit, Units::petabit,
+    Units::exabit, Units::zettabit, Units::yottabit,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static BINARY_BYTES: &[Units] = &[
+    Units::byte, Units::kibibyte, Units::mebibyte, Units::gibibyte, Units::tebibyte,
+    Units::pebibyte, Units::exbibyte, Units::zebibyte, Units::yobibyte,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static DECIMAL_BYTES: &[Units] = &[
+    Units::byte, Units::kilobyte, Units::megabyte, Units::gigabyte, Units::terabyte,
+    Units::petabyte, Units::exabyte, Units::zettabyte, Units::yottabyte,
+];
+
+/// Splits `s` into a value and unit. Returns `None` unless `s` is a single value followed by a
+/// unit, with or without whitespace between them.
+#[allow(dead_code)]
+pub(super) fn value_unit(s: &str) -> Option<(&str, &str)> {
+    let mut terms = crate::str::terms(s);
+
+    match (terms.next(), terms.next()) {
+        (Some(Ok(term)), None) => Some(term),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `unit` is an alternative spelling of the bit or byte unit `abbreviation`,
+/// e.g. `KB` for `kB`, `Mbit` for `Mb`, or `Mbps` for `Mb/s`. Prefixes are compared ignoring case
+/// while `b` and `B` distinguish bits from bytes.
+#[allow(dead_code)]
+pub(super) fn abbreviation_eq(unit: &str, abbreviation: &str) -> bool {
+    /// Unit suffixes and the bit or byte symbol they represent. Spelled out suffixes are compared
+    /// ignoring case.
+    const SUFFIXES: &[(&str, char)] =
+        &[("bits", 'b'), ("bit", 'b'), ("bytes", 'B'), ("byte", 'B'), ("b", 'b'), ("B", 'B')];
+
+    /// Splits a unit into its prefix, `b` or `B`, and whether the unit is per second.
+    fn split(unit: &str) -> Option<(&str, char, bool)> {
+        let (unit, per_second) = match unit.strip_suffix("/s").or_else(|| unit.strip_suffix("ps")) {
+            Some(unit) => (unit, true),
+            None => (unit, false),
+        };
+
+        SUFFIXES.iter().find_map(|&(suffix, symbol)| {
+            let at = unit.len().checked_sub(suffix.len()).filter(|&at| unit.is_char_boundary(at))?;
+            let (prefix, rest) = unit.split_at(at);
+            let matches =
+                if suffix.len() > 1 { rest.eq_ignore_ascii_case(suffix) } else { rest == suffix };
+
+            matches.then_some((prefix, symbol, per_second))
+        })
+    }
+
+    match (split(unit), split(abbreviation)) {
+        (Some((prefix, base, per_second)), Some((p, b, ps))) => {
+            prefix.eq_ignore_ascii_case(p) && base == b && per_second == ps
+        }
+        _ => false,
+    }
+}
+
+mod scaled {
+    storage_types! {
+        types: Float;
+
+        use super::super::{InformationUnit, Scaling, Units};
+        use crate::fmt::{DisplayStyle, Prefixes};
+        use crate::num::FromPrimitive;
+        use crate::si::fmt::PrefixedArguments;
+        use crate::str::{ParseOptions, ParseQuantityError};
+
+        impl<U> super::super::Information<U, V>
+        where
+            U: crate::si::Units<V> + ?Sized,
+        {
+            /// Creates a struct that formats `self` for display in the bit or byte unit with the
+            /// largest binary or decimal prefix that displays a value greater than or equal to
+            /// one, e.g. "1.5 GiB". The value is rounded to the precision of the formatter, e.g.
+            /// `{:.1}`. See
+            /// [`into_prefixed_format_args`](#method.into_prefixed_format_args).
+            ///
+            /// # Examples
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::information::{byte, InformationUnit::*, Scaling::*};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// let i = Information::new::<byte>(1_610_612_736.0);
+            ///
+            /// assert_eq!("1.5 GiB",
+            ///     format!("{:.1}", i.into_scaled_format_args(Binary, Byte, Abbreviation)));
+            /// assert_eq!("1.6 GB",
+            ///     format!("{:.1}", i.into_scaled_format_args(Decimal, Byte, Abbreviation)));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_scaled_format_args(
+                self,
+                scaling: Scaling,
+                unit: InformationUnit,
+                style: DisplayStyle,
+            ) -> PrefixedArguments<'static, super::super::Dimension, U, V, Units> {
+                let units = match (scaling, unit) {
+                    (Scaling::Binary, InformationUnit::Bit) => super::super::BINARY_BITS,
+                    (Scaling::Decimal, InformationUnit::Bit) => super::super::DECIMAL_BITS,
+                    (Scaling::Binary, InformationUnit::Byte) => super::super::BINARY_BYTES,
+                    (Scaling::Decimal, InformationUnit::Byte) => super::super::DECIMAL_BYTES,
+                };
+
+                self.into_prefixed_format_args(units[0], Prefixes::Only(units), style)
+            }
+
+            /// Parses a string into information, accepting common alternative spellings of bit
+            /// and byte units. The string is first parsed with
+            /// [`ParseOptions::lenient`](crate::str::ParseOptions::lenient). Otherwise prefixes are
+            /// compared ignoring case, e.g. `KB` or `mib`, and units may be spelled out, e.g.
+            /// `Mbit` or `GiBytes`. `b` is a bit and `B` is a byte.
+            ///
+            /// # Errors
+            /// Returns a [`ParseQuantityError`](crate::str::ParseQuantityError) when the string
+            /// can't be parsed.
+            ///
+            /// # Examples
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::information::{bit, gibibyte, kilobyte};
+            /// let i = Information::from_str_lenient("1.5GiB").unwrap();
+            ///
+            /// assert_eq!(1.5, i.get::<gibibyte>());
+            /// assert_eq!(10.0, Information::from_str_lenient("10 KB").unwrap().get::<kilobyte>());
+            /// assert_eq!(5.0E6, Information::from_str_lenient("5 Mbit").unwrap().get::<bit>());
+            /// ```
+            pub fn from_str_lenient(s: &str) -> Result<Self, ParseQuantityError> {
+                Self::from_str_with(s, ParseOptions::lenient()).or_else(|e| {
+                    super::super::value_unit(s)
+                        .and_then(|(value, unit)| {
+                            let unit = super::super::units()
+                                .find(|u| super::super::abbreviation_eq(unit, u.abbreviation()))?;
+
+                            Some(value.parse::<V>().ok()? * V::from_f64(unit.coefficient_f64())?)
+                        })
+                        .map(Self::new::<super::super::byte>)
+                        .ok_or(e)
+                })
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use crate::fmt::DisplayStyle::*;
+        use crate::si::information::{self as i, InformationUnit, Scaling};
+        use crate::si::quantities::*;
+        use crate::str::ParseQuantityErrorKind;
+
+        #[test]
+        fn scaled_format_args() {
+            let f = |v: V, scaling, unit| {
+                format!("{:.1}", Information::<V>::new::<i::byte>(v)
+                    .into_scaled_format_args(scaling, unit, Abbreviation))
+            };
+
+            assert_eq!("1.5 GiB", f(1_610_612_736.0, Scaling::Binary, InformationUnit::Byte));
+            assert_eq!("1.6 GB", f(1_610_612_736.0, Scaling::Decimal, InformationUnit::Byte));
+            assert_eq!("12.0 Gib", f(1_610_612_736.0, Scaling::Binary, InformationUnit::Bit));
+            assert_eq!("900.0 B", f(900.0, Scaling::Binary, InformationUnit::Byte));
+            assert_eq!("1.0 KiB", f(1_023.0, Scaling::Binary, InformationUnit::Byte));
+            assert_eq!("1.0 kB", f(1_000.0, Scaling::Decimal, InformationUnit::Byte));
+            assert_eq!("4.0 b", f(0.5, Scaling::Decimal, InformationUnit::Bit));
+            assert_eq!("0.0 B", f(0.0, Scaling::Binary, InformationUnit::Byte));
+            assert_eq!("1 kibibyte", format!("{}", Information::<V>::new::<i::kibibyte>(1.0)
+                .into_scaled_format_args(Scaling::Binary, InformationUnit::Byte, Description)));
+        }
+
+        #[test]
+        fn from_str_lenient() {
+            let p = |s| Information::<V>::from_str_lenient(s).unwrap().get::<i::byte>();
+
+            assert_eq!(1_610_612_736.0, p("1.5GiB"));
+            assert_eq!(1_610_612_736.0, p("1.5 gib") * 8.0);
+            assert_eq!(10.0E6, p("10 MB"));
+            assert_eq!(10.0E6, p("10 mB"));
+            assert_eq!(10.0E3, p("10 KB"));
+            assert_eq!(10.0E3, p("10kbytes"));
+            assert_eq!(1.25E6, p("10 Mbit"));
+            assert_eq!(1_024.0, p("1 KiByte"));
+            assert_eq!(1.0, p("1 o"));
+
+            assert_eq!(ParseQuantityErrorKind::UnknownUnit,
+                Information::<V>::from_str_lenient("10 Mbps").unwrap_err().kind());
+            assert_eq!(ParseQuantityErrorKind::UnknownUnit,
+                Information::<V>::from_str_lenient("10 MX").unwrap_err().kind());
+            assert!(Information::<V>::from_str_lenient("10 MB 5 kB").is_err());
+        }
+    }
+}
@@ -72,6 +72,119 @@ quantity! {
     }
 }
 
+#[allow(dead_code)]
+#[rustfmt::skip]
+static BINARY_BITS: &[Units] = &[
+    Units::bit_per_second, Units::kibibit_per_second, Units::mebibit_per_second,
+    Units::gibibit_per_second, Units::tebibit_per_second, Units::pebibit_per_second,
+    Units::exbibit_per_second, Units::zebibit_per_second, Units::yobibit_per_second,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static DECIMAL_BITS: &[Units] = &[
+    Units::bit_per_second, Units::kilobit_per_second, Units::megabit_per_second,
+    Units::gigabit_per_second, Units::terabit_per_second, Units::petabit_per_second,
+    Units::exabit_per_second, Units::zettabit_per_second, Units::yottabit_per_second,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static BINARY_BYTES: &[Units] = &[
+    Units::byte_per_second, Units::kibibyte_per_second, Units::mebibyte_per_second,
+    Units::gibibyte_per_second, Units::tebibyte_per_second, Units::pebibyte_per_second,
+    Units::exbibyte_per_second, Units::zebibyte_per_second, Units::yobibyte_per_second,
+];
+#[allow(dead_code)]
+#[rustfmt::skip]
+static DECIMAL_BYTES: &[Units] = &[
+    Units::byte_per_second, Units::kilobyte_per_second, Units::megabyte_per_second,
+    Units::gigabyte_per_second, Units::terabyte_per_second, Units::petabyte_per_second,
+    Units::exabyte_per_second, Units::zettabyte_per_second, Units::yottabyte_per_second,
+];
+
+mod scaled {
+    storage_types! {
+        types: Float;
+
+        use super::super::Units;
+        use crate::fmt::{DisplayStyle, Prefixes};
+        use crate::num::FromPrimitive;
+        use crate::si::fmt::PrefixedArguments;
+        use crate::si::information::{abbreviation_eq, value_unit, InformationUnit, Scaling};
+        use crate::str::{ParseOptions, ParseQuantityError};
+
+        impl<U> super::super::InformationRate<U, V>
+        where
+            U: crate::si::Units<V> + ?Sized,
+        {
+            /// Creates a struct that formats `self` for display in the bit or byte per second unit
+            /// with the largest binary or decimal prefix that displays a value greater than or
+            /// equal to one, e.g. "300 Mb/s". The value is rounded to the precision of the
+            /// formatter, e.g. `{:.1}`. See
+            /// [`into_prefixed_format_args`](#method.into_prefixed_format_args).
+            ///
+            /// # Examples
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::information::{InformationUnit::*, Scaling::*};
+            /// # use uom::si::information_rate::byte_per_second;
+            /// # use uom::fmt::DisplayStyle::*;
+            /// let r = InformationRate::new::<byte_per_second>(37.5E6);
+            /// let a = r.into_scaled_format_args(Decimal, Bit, Abbreviation);
+            ///
+            /// assert_eq!("300 Mb/s", format!("{}", a));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_scaled_format_args(
+                self,
+                scaling: Scaling,
+                unit: InformationUnit,
+                style: DisplayStyle,
+            ) -> PrefixedArguments<'static, super::super::Dimension, U, V, Units> {
+                let units = match (scaling, unit) {
+                    (Scaling::Binary, InformationUnit::Bit) => super::super::BINARY_BITS,
+                    (Scaling::Decimal, InformationUnit::Bit) => super::super::DECIMAL_BITS,
+                    (Scaling::Binary, InformationUnit::Byte) => super::super::BINARY_BYTES,
+                    (Scaling::Decimal, InformationUnit::Byte) => super::super::DECIMAL_BYTES,
+                };
+
+                self.into_prefixed_format_args(units[0], Prefixes::Only(units), style)
+            }
+
+            /// Parses a string into an information rate, accepting common alternative spellings
+            /// of bit and byte per second units, e.g. `100 Mbps`, `10MBps`, or `1 Gbit/s`. Units
+            /// are resolved as for information with `ps` or `/s` appended.
+            ///
+            /// # Errors
+            /// Returns a [`ParseQuantityError`](crate::str::ParseQuantityError) when the string
+            /// can't be parsed.
+            ///
+            /// # Examples
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::information_rate::megabit_per_second;
+            /// let r = InformationRate::from_str_lenient("100 Mbps").unwrap();
+            ///
+            /// assert_eq!(100.0, r.get::<megabit_per_second>());
+            /// ```
+            pub fn from_str_lenient(s: &str) -> Result<Self, ParseQuantityError> {
+                Self::from_str_with(s, ParseOptions::lenient()).or_else(|e| {
+                    value_unit(s)
+                        .and_then(|(value, unit)| {
+                            let unit = super::super::units()
+                                .find(|u| abbreviation_eq(unit, u.abbreviation()))?;
+
+                            Some(value.parse::<V>().ok()? * V::from_f64(unit.coefficient_f64())?)
+                        })
+                        .map(Self::new::<super::super::byte_per_second>)
+                        .ok_or(e)
+                })
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     storage_types! {
@@ -128,4 +241,45 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "std")]
+    mod scaled {
+        storage_types! {
+            types: Float;
+
+            use crate::fmt::DisplayStyle::*;
+            use crate::si::information::{InformationUnit, Scaling};
+            use crate::si::information_rate as r;
+            use crate::si::quantities::*;
+
+            #[test]
+            fn scaled_format_args() {
+                let f = |v: V, scaling, unit| {
+                    format!("{}", InformationRate::<V>::new::<r::byte_per_second>(v)
+                        .into_scaled_format_args(scaling, unit, Abbreviation))
+                };
+
+                assert_eq!("300 Mb/s", f(37.5E6, Scaling::Decimal, InformationUnit::Bit));
+                assert_eq!("37.5 MB/s", f(37.5E6, Scaling::Decimal, InformationUnit::Byte));
+                assert_eq!("2 KiB/s", f(2_048.0, Scaling::Binary, InformationUnit::Byte));
+                assert_eq!("4 b/s", f(0.5, Scaling::Binary, InformationUnit::Bit));
+            }
+
+            #[test]
+            fn from_str_lenient() {
+                let p = |s| InformationRate::<V>::from_str_lenient(s).unwrap()
+                    .get::<r::bit_per_second>();
+
+                assert_eq!(100.0E6, p("100 Mbps"));
+                assert_eq!(100.0E6, p("100Mbit/s"));
+                assert_eq!(80.0E6, p("10 MBps"));
+                assert_eq!(80.0E6, p("10 MB/s"));
+                assert_eq!(1_024.0, p("1 kibps"));
+                assert_eq!(1.0, p("1 bps"));
+
+                assert!(InformationRate::<V>::from_str_lenient("100 Mb").is_err());
+                assert!(InformationRate::<V>::from_str_lenient("Mbps").is_err());
+            }
+        }
+    }
 }
@@ -13,51 +13,55 @@ quantity! {
         Z0,     // amount of substance
         Z0>;    // luminous intensity
     units {
-        @yottameter: prefix!(yotta); "Ym", "yottameter", "yottameters";
-        @zettameter: prefix!(zetta); "Zm", "zettameter", "zettameters";
-        @exameter: prefix!(exa); "Em", "exameter", "exameters";
-        @petameter: prefix!(peta); "Pm", "petameter", "petameters";
-        @terameter: prefix!(tera); "Tm", "terameter", "terameters";
-        @gigameter: prefix!(giga); "Gm", "gigameter", "gigameters";
-        @megameter: prefix!(mega); "Mm", "megameter", "megameters";
-        @kilometer: prefix!(kilo); "km", "kilometer", "kilometers";
-        @hectometer: prefix!(hecto); "hm", "hectometer", "hectometers";
-        @decameter: prefix!(deca); "dam", "decameter", "decameters";
+        @yottameter: prefix!(yotta) => prefix!(exact yotta); "Ym", "yottameter", "yottameters";
+        @zettameter: prefix!(zetta) => prefix!(exact zetta); "Zm", "zettameter", "zettameters";
+        @exameter: prefix!(exa) => prefix!(exact exa); "Em", "exameter", "exameters";
+        @petameter: prefix!(peta) => prefix!(exact peta); "Pm", "petameter", "petameters";
+        @terameter: prefix!(tera) => prefix!(exact tera); "Tm", "terameter", "terameters";
+        @gigameter: prefix!(giga) => prefix!(exact giga); "Gm", "gigameter", "gigameters";
+        @megameter: prefix!(mega) => prefix!(exact mega); "Mm", "megameter", "megameters";
+        @kilometer: prefix!(kilo) => prefix!(exact kilo); "km", "kilometer", "kilometers",
+            r"\kilo\meter";
+        @hectometer: prefix!(hecto) => prefix!(exact hecto); "hm", "hectometer", "hectometers";
+        @decameter: prefix!(deca) => prefix!(exact deca); "dam", "decameter", "decameters";
         /// The meter is the SI unit of length. It is defined by taking the fixed numerical value
         /// of the speed of light in vacuum *c* to be 299 792 458 when expressed in the unit m s⁻¹,
         /// where the second is defined in terms of the caesium frequency ∆*ν*<sub>Cs</sub>.
-        @meter: prefix!(none); "m", "meter", "meters";
-        @decimeter: prefix!(deci); "dm", "decimeter", "decimeters";
-        @centimeter: prefix!(centi); "cm", "centimeter", "centimeters";
-        @millimeter: prefix!(milli); "mm", "millimeter", "millimeters";
-        @micrometer: prefix!(micro); "µm", "micrometer", "micrometers";
-        @nanometer: prefix!(nano); "nm", "nanometer", "nanometers";
-        @picometer: prefix!(pico); "pm", "picometer", "picometers";
-        @femtometer: prefix!(femto); "fm", "femtometer", "femtometers";
-        @attometer: prefix!(atto); "am", "attometer", "attometers";
-        @zeptometer: prefix!(zepto); "zm", "zeptometer", "zeptometers";
-        @yoctometer: prefix!(yocto); "ym", "yoctometer", "yoctometers";
+        @meter: prefix!(none) => prefix!(exact none); "m", "meter", "meters", r"\meter";
+        @decimeter: prefix!(deci) => prefix!(exact deci); "dm", "decimeter", "decimeters";
+        @centimeter: prefix!(centi) => prefix!(exact centi); "cm", "centimeter", "centimeters";
+        @millimeter: prefix!(milli) => prefix!(exact milli); "mm", "millimeter", "millimeters";
+        @micrometer: prefix!(micro) => prefix!(exact micro); "µm", "micrometer", "micrometers";
+        @nanometer: prefix!(nano) => prefix!(exact nano); "nm", "nanometer", "nanometers";
+        @picometer: prefix!(pico) => prefix!(exact pico); "pm", "picometer", "picometers";
+        @femtometer: prefix!(femto) => prefix!(exact femto); "fm", "femtometer", "femtometers";
+        @attometer: prefix!(atto) => prefix!(exact atto); "am", "attometer", "attometers";
+        @zeptometer: prefix!(zepto) => prefix!(exact zepto); "zm", "zeptometer", "zeptometers";
+        @yoctometer: prefix!(yocto) => prefix!(exact yocto); "ym", "yoctometer", "yoctometers";
 
-        @angstrom: 1.0_E-10; "Å", "ångström", "ångströms";
+        @angstrom: 1.0_E-10 => crate::ExactFactor::pow10(-10); "Å", "ångström", "ångströms";
         @bohr_radius: 5.291_772_109_03_E-11; "a₀", "bohr radius", "bohr radiuses";
         /// Atomic unit of length (Bohr radius).
         @atomic_unit_of_length: 5.291_772_109_03_E-11; "a.u. of length", "atomic unit of length",
             "atomic units of length";
         @astronomical_unit: 1.495_978_707_E11; "ua", "astronomical unit", "astronomical units";
-        @chain: 2.011_684_E1; "ch", "chain", "chains";
+        @chain: 2.011_684_E1 => crate::ExactFactor::new(2_011_684, 100_000); "ch", "chain",
+            "chains";
         @data_mile: 1.828_8_E3; "DM", "data mile", "data miles";
         @fathom: 1.828_804_E0; "fathom", "fathom", "fathoms";
-        @fermi: 1.0_E-15; "fermi", "fermi", "fermis";
-        @foot: 3.048_E-1; "ft", "foot", "feet";
+        @fermi: 1.0_E-15 => prefix!(exact femto); "fermi", "fermi", "fermis";
+        @foot: 3.048_E-1 => crate::ExactFactor::new(3_048, 10_000); "ft", "foot", "feet";
         @foot_survey: 3.048_006_E-1; "ft (U.S. survey)", "foot (U.S. survey)", "feet (U.S. survey)";
-        @inch: 2.54_E-2; "in", "inch", "inches";
+        @inch: 2.54_E-2 => crate::ExactFactor::new(254, 10_000); "in", "inch", "inches";
         @light_year: 9.460_73_E15; "l. y.", "light year", "light years";
-        @microinch: 2.54_E-8; "μin", "microinch", "microinches";
-        @micron: 1.0_E-6; "μ", "micron", "microns";
-        @mil: 2.54_E-5; "0.001 in", "mil", "mils";
-        @mile: 1.609_344_E3; "mi", "mile", "miles";
+        @microinch: 2.54_E-8 => crate::ExactFactor::new(254, 10_000_000_000); "μin", "microinch",
+            "microinches";
+        @micron: 1.0_E-6 => prefix!(exact micro); "μ", "micron", "microns";
+        @mil: 2.54_E-5 => crate::ExactFactor::new(254, 10_000_000); "0.001 in", "mil", "mils";
+        @mile: 1.609_344_E3 => crate::ExactFactor::new(1_609_344, 1_000); "mi", "mile", "miles";
         @mile_survey: 1.609_347_E3; "mi (U.S. survey)", "mile (U.S. survey)", "miles (U.S. survey)";
-        @nautical_mile: 1.852_E3; "M", "nautical mile", "nautical miles";
+        @nautical_mile: 1.852_E3 => crate::ExactFactor::new(1_852, 1); "M", "nautical mile",
+            "nautical miles";
         @parsec: 3.085_678_E16; "pc", "parsec", "parsecs";
         @pica_computer: 4.233_333_333_333_333_E-3; "1/6 in (computer)", "pica (computer)",
             "picas (computer)";
@@ -66,7 +70,7 @@ quantity! {
             "points (computer)";
         @point_printers: 3.514_598_E-4; "1/72 in", "point (printer's)", "points (printer's)";
         @rod: 5.029_21_E0; "rd", "rod", "rods";
-        @yard: 9.144_E-1; "yd", "yard", "yards";
+        @yard: 9.144_E-1 => crate::ExactFactor::new(9_144, 10_000); "yd", "yard", "yards";
 
         // Ancient Roman units.
         @leuga: 2.22_E3; "leuga", "leuga", "leugae";
@@ -116,4 +120,30 @@ mod tests {
             }
         }
     }
+
+    mod exact {
+        storage_types! {
+            types: i32, i64, i128, u32, u64, u128, BigInt, BigUint, Ratio;
+
+            use crate::num::FromPrimitive;
+            use crate::si::length as l;
+            use crate::si::quantities::*;
+
+            #[test]
+            fn check_exact() {
+                test::<l::inch, l::micrometer>(5_000, 127_000_000);
+                test::<l::foot, l::meter>(1_250, 381);
+                test::<l::yard, l::millimeter>(1_250, 1_143_000);
+                test::<l::mile, l::meter>(125, 201_168);
+                test::<l::nautical_mile, l::meter>(1, 1_852);
+                test::<l::meter, l::inch>(127, 5_000);
+                test::<l::kilometer, l::micrometer>(1, 1_000_000_000);
+
+                fn test<I: l::Conversion<V>, O: l::Conversion<V>>(i: u32, o: u32) {
+                    assert_eq!(V::from_u32(o).unwrap(),
+                        Length::new::<I>(V::from_u32(i).unwrap()).get::<O>());
+                }
+            }
+        }
+    }
 }
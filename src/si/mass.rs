@@ -24,10 +24,10 @@ quantity! {
         /// of the Planck constant *h* to be 6.626 070 15 × 10⁻³⁴ when expressed in the unit J s,
         /// which is equal to kg m² s⁻¹, where the meter and the second are defined in terms of *c*
         /// and ∆*ν*<sub>Cs</sub>.
-        @kilogram: prefix!(kilo) / prefix!(kilo); "kg", "kilogram", "kilograms";
+        @kilogram: prefix!(kilo) / prefix!(kilo); "kg", "kilogram", "kilograms", r"\kilogram";
         @hectogram: prefix!(hecto) / prefix!(kilo); "hg", "hectogram", "hectograms";
         @decagram: prefix!(deca) / prefix!(kilo); "dag", "decagram", "decagrams";
-        @gram: prefix!(none) / prefix!(kilo); "g", "gram", "grams";
+        @gram: prefix!(none) / prefix!(kilo); "g", "gram", "grams", r"\gram";
         @decigram: prefix!(deci) / prefix!(kilo); "dg", "decigram", "decigrams";
         @centigram: prefix!(centi) / prefix!(kilo); "cg", "centigram", "centigrams";
         @milligram: prefix!(milli) / prefix!(kilo); "mg", "milligram", "milligrams";
@@ -5,6 +5,9 @@
 /// generate the appropriate float type. Using explicit constants would require duplicate
 /// definitions for `f32` and `f64` or casting from `f64` in `f32` contexts.
 ///
+/// Prefixes preceded by `exact`, e.g. `prefix!(exact milli)`, expand to the equivalent
+/// [`ExactFactor`](crate::ExactFactor) for use as the exact coefficient of a unit.
+///
 /// [si]: https://jcgm.bipm.org/vim/en/1.16.html
 /// [mult]: https://jcgm.bipm.org/vim/en/1.17.html
 /// [submult]: https://jcgm.bipm.org/vim/en/1.18.html
@@ -44,4 +47,37 @@ macro_rules! prefix {
     (gibi) => { 1024.0 * 1024.0 * 1024.0 };
     (mebi) => { 1024.0 * 1024.0 };
     (kibi) => { 1024.0 };
+
+    // Exact SI prefixes.
+    (exact yotta) => { $crate::ExactFactor::pow10(24) };
+    (exact zetta) => { $crate::ExactFactor::pow10(21) };
+    (exact exa) => { $crate::ExactFactor::pow10(18) };
+    (exact peta) => { $crate::ExactFactor::pow10(15) };
+    (exact tera) => { $crate::ExactFactor::pow10(12) };
+    (exact giga) => { $crate::ExactFactor::pow10(9) };
+    (exact mega) => { $crate::ExactFactor::pow10(6) };
+    (exact kilo) => { $crate::ExactFactor::pow10(3) };
+    (exact hecto) => { $crate::ExactFactor::pow10(2) };
+    (exact deca) => { $crate::ExactFactor::pow10(1) };
+    (exact none) => { $crate::ExactFactor::pow10(0) };
+    (exact deci) => { $crate::ExactFactor::pow10(-1) };
+    (exact centi) => { $crate::ExactFactor::pow10(-2) };
+    (exact milli) => { $crate::ExactFactor::pow10(-3) };
+    (exact micro) => { $crate::ExactFactor::pow10(-6) };
+    (exact nano) => { $crate::ExactFactor::pow10(-9) };
+    (exact pico) => { $crate::ExactFactor::pow10(-12) };
+    (exact femto) => { $crate::ExactFactor::pow10(-15) };
+    (exact atto) => { $crate::ExactFactor::pow10(-18) };
+    (exact zepto) => { $crate::ExactFactor::pow10(-21) };
+    (exact yocto) => { $crate::ExactFactor::pow10(-24) };
+
+    // Exact binary prefixes.
+    (exact yobi) => { $crate::ExactFactor::new(1 << 80, 1) };
+    (exact zebi) => { $crate::ExactFactor::new(1 << 70, 1) };
+    (exact exbi) => { $crate::ExactFactor::new(1 << 60, 1) };
+    (exact pebi) => { $crate::ExactFactor::new(1 << 50, 1) };
+    (exact tebi) => { $crate::ExactFactor::new(1 << 40, 1) };
+    (exact gibi) => { $crate::ExactFactor::new(1 << 30, 1) };
+    (exact mebi) => { $crate::ExactFactor::new(1 << 20, 1) };
+    (exact kibi) => { $crate::ExactFactor::new(1 << 10, 1) };
 }
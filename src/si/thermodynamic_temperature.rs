@@ -61,36 +61,40 @@ quantity! {
         Z0>;    // luminous intensity
     kind: dyn crate::si::marker::TemperatureKind;
     units {
-        @yottakelvin: prefix!(yotta); "YK", "yottakelvin", "yottakelvins";
-        @zettakelvin: prefix!(zetta); "ZK", "zettakelvin", "zettakelvins";
-        @exakelvin: prefix!(exa); "EK", "exakelvin", "exakelvins";
-        @petakelvin: prefix!(peta); "PK", "petakelvin", "petakelvins";
-        @terakelvin: prefix!(tera); "TK", "terakelvin", "terakelvins";
-        @gigakelvin: prefix!(giga); "GK", "gigakelvin", "gigakelvins";
-        @megakelvin: prefix!(mega); "MK", "megakelvin", "megakelvins";
-        @kilokelvin: prefix!(kilo); "kK", "kilokelvin", "kilokelvins";
-        @hectokelvin: prefix!(hecto); "hK", "hectokelvin", "hectokelvins";
-        @decakelvin: prefix!(deca); "daK", "decakelvin", "decakelvins";
+        @yottakelvin: prefix!(yotta) => prefix!(exact yotta); "YK", "yottakelvin", "yottakelvins";
+        @zettakelvin: prefix!(zetta) => prefix!(exact zetta); "ZK", "zettakelvin", "zettakelvins";
+        @exakelvin: prefix!(exa) => prefix!(exact exa); "EK", "exakelvin", "exakelvins";
+        @petakelvin: prefix!(peta) => prefix!(exact peta); "PK", "petakelvin", "petakelvins";
+        @terakelvin: prefix!(tera) => prefix!(exact tera); "TK", "terakelvin", "terakelvins";
+        @gigakelvin: prefix!(giga) => prefix!(exact giga); "GK", "gigakelvin", "gigakelvins";
+        @megakelvin: prefix!(mega) => prefix!(exact mega); "MK", "megakelvin", "megakelvins";
+        @kilokelvin: prefix!(kilo) => prefix!(exact kilo); "kK", "kilokelvin", "kilokelvins";
+        @hectokelvin: prefix!(hecto) => prefix!(exact hecto); "hK", "hectokelvin", "hectokelvins";
+        @decakelvin: prefix!(deca) => prefix!(exact deca); "daK", "decakelvin", "decakelvins";
         /// The kelvin is the SI unit of thermodynamic temperature. It is defined by taking the
         /// fixed numerical value of the Boltzmann constant *k* to be 1.380 649 × 10⁻²³ when
         /// expressed in the unit J K⁻¹, which is equal to kg m² s⁻² K⁻¹, where the kilogram, meter,
         /// and second are defined in terms of *h*, *c*, and ∆*ν*<sub>Cs</sub>.
-        @kelvin: prefix!(none); "K", "kelvin", "kelvins";
-        @decikelvin: prefix!(deci); "dK", "decikelvin", "decikelvins";
-        @centikelvin: prefix!(centi); "cK", "centikelvin", "centikelvins";
-        @millikelvin: prefix!(milli); "mK", "millikelvin", "millikelvins";
-        @microkelvin: prefix!(micro); "µK", "microkelvin", "microkelvins";
-        @nanokelvin: prefix!(nano); "nK", "nanokelvin", "nanokelvins";
-        @picokelvin: prefix!(pico); "pK", "picokelvin", "picokelvins";
-        @femtokelvin: prefix!(femto); "fK", "femtokelvin", "femtokelvins";
-        @attokelvin: prefix!(atto); "aK", "attokelvin", "attokelvins";
-        @zeptokelvin: prefix!(zepto); "zK", "zeptokelvin", "zeptokelvins";
-        @yoctokelvin: prefix!(yocto); "yK", "yoctokelvin", "yoctokelvins";
-
-        @degree_celsius: 1.0_E0, 273.15_E0; "°C", "degree Celsius", "degrees Celsius";
-        @degree_fahrenheit: 5.0_E0 / 9.0_E0, 459.67_E0; "°F", "degree Fahrenheit",
+        @kelvin: prefix!(none) => prefix!(exact none); "K", "kelvin", "kelvins", r"\kelvin";
+        @decikelvin: prefix!(deci) => prefix!(exact deci); "dK", "decikelvin", "decikelvins";
+        @centikelvin: prefix!(centi) => prefix!(exact centi); "cK", "centikelvin", "centikelvins";
+        @millikelvin: prefix!(milli) => prefix!(exact milli); "mK", "millikelvin", "millikelvins";
+        @microkelvin: prefix!(micro) => prefix!(exact micro); "µK", "microkelvin", "microkelvins";
+        @nanokelvin: prefix!(nano) => prefix!(exact nano); "nK", "nanokelvin", "nanokelvins";
+        @picokelvin: prefix!(pico) => prefix!(exact pico); "pK", "picokelvin", "picokelvins";
+        @femtokelvin: prefix!(femto) => prefix!(exact femto); "fK", "femtokelvin", "femtokelvins";
+        @attokelvin: prefix!(atto) => prefix!(exact atto); "aK", "attokelvin", "attokelvins";
+        @zeptokelvin: prefix!(zepto) => prefix!(exact zepto); "zK", "zeptokelvin", "zeptokelvins";
+        @yoctokelvin: prefix!(yocto) => prefix!(exact yocto); "yK", "yoctokelvin", "yoctokelvins";
+
+        @degree_celsius: 1.0_E0 => crate::ExactFactor::new(1, 1),
+            273.15_E0 => crate::ExactFactor::new(27_315, 100); "°C", "degree Celsius",
+            "degrees Celsius", r"\degreeCelsius";
+        @degree_fahrenheit: 5.0_E0 / 9.0_E0 => crate::ExactFactor::new(5, 9),
+            459.67_E0 => crate::ExactFactor::new(45_967, 100); "°F", "degree Fahrenheit",
             "degrees Fahrenheit";
-        @degree_rankine: 5.0_E0 / 9.0_E0; "°R", "degree Rankine", "degrees Rankine";
+        @degree_rankine: 5.0_E0 / 9.0_E0 => crate::ExactFactor::new(5, 9); "°R", "degree Rankine",
+            "degrees Rankine";
     }
 }
 
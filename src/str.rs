@@ -0,0 +1,2024 @@
+//! Unicode string slice manipulation for quantities.
+
+use crate::lib::any::TypeId;
+use crate::lib::fmt::{self, Display, Formatter};
+use crate::lib::ops::Range;
+use crate::ExactFactor;
+
+/// Kind of error encountered while parsing a string into a `Quantity`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseQuantityErrorKind {
+    /// No separators (spaces) were encountered.
+    NoSeparator,
+
+    /// An error occurred while parsing the value (first) portion of the string. See
+    /// [`ParseQuantityError::value_error`].
+    ValueParseError,
+
+    /// The unit used wasn't found for this quantity. See [`ParseQuantityError::unit`],
+    /// [`ParseQuantityError::suggestions`], and [`ParseQuantityError::other_quantity`].
+    ///
+    /// ### Notes
+    /// Units are matched by abbreviation, singular, or plural description. Unit expressions, e.g.
+    /// `m/s^2`, are resolved against the units of all quantities in the system and must have the
+    /// same dimension as the quantity. Units of a specific kind, e.g. `rad`, must also match the
+    /// kind of the quantity.
+    UnknownUnit,
+}
+
+/// Represents an error encountered while parsing a string into a `Quantity`.
+///
+#[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+/// # use uom::si::f32::*;
+/// # use uom::str::ParseQuantityErrorKind;
+/// let e = "5 kmh".parse::<Length>().unwrap_err();
+///
+/// assert_eq!(ParseQuantityErrorKind::UnknownUnit, e.kind());
+/// assert_eq!(2..5, e.span());
+/// assert_eq!(Some("kmh"), e.unit());
+/// assert_eq!("length", e.quantity());
+/// assert_eq!(Some("km"), e.suggestions().next());
+///
+/// let e = "5 s".parse::<Length>().unwrap_err();
+///
+/// assert_eq!(Some("time"), e.other_quantity());
+/// ```
+///
+/// ### Notes
+/// `ParseQuantityError` was previously an enum. Code matching on its variants should match on
+/// [`kind`](Self::kind) instead, e.g. `e.kind() == ParseQuantityErrorKind::UnknownUnit` in place
+/// of `e == ParseQuantityError::UnknownUnit`.
+#[derive(Clone)]
+pub struct ParseQuantityError {
+    kind: ParseQuantityErrorKind,
+    span: Range<usize>,
+    text: Text,
+    quantity: &'static str,
+    other_quantity: Option<&'static str>,
+    unit_names: fn(&mut dyn FnMut(&'static str)),
+}
+
+/// Maximum number of suggestions for an unknown unit.
+const MAX_SUGGESTIONS: usize = 3;
+
+impl ParseQuantityError {
+    /// Creates a [`ParseQuantityErrorKind::NoSeparator`] error.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn no_separator(quantity: &'static str, span: Range<usize>) -> Self {
+        Self::new(ParseQuantityErrorKind::NoSeparator, quantity, span)
+    }
+
+    /// Creates a [`ParseQuantityErrorKind::ValueParseError`] error with the given underlying
+    /// error.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn value_parse_error(
+        quantity: &'static str,
+        span: Range<usize>,
+        error: &dyn Display,
+    ) -> Self {
+        let mut e = Self::new(ParseQuantityErrorKind::ValueParseError, quantity, span);
+        let _ = fmt::Write::write_fmt(&mut e.text, format_args!("{}", error));
+
+        e
+    }
+
+    /// Creates a [`ParseQuantityErrorKind::UnknownUnit`] error.
+    ///
+    /// * `unit_names`: Calls the given callback with the abbreviation, singular, and plural
+    ///   description of every unit of the quantity.
+    /// * `other_quantity`: Description of another quantity that has a unit matching `unit`.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn unknown_unit(
+        quantity: &'static str,
+        span: Range<usize>,
+        unit: &str,
+        unit_names: fn(&mut dyn FnMut(&'static str)),
+        other_quantity: Option<&'static str>,
+    ) -> Self {
+        let mut e = Self::new(ParseQuantityErrorKind::UnknownUnit, quantity, span);
+        let _ = fmt::Write::write_str(&mut e.text, unit);
+
+        e.unit_names = unit_names;
+        e.other_quantity = other_quantity;
+
+        e
+    }
+
+    fn new(kind: ParseQuantityErrorKind, quantity: &'static str, span: Range<usize>) -> Self {
+        ParseQuantityError {
+            kind,
+            span,
+            text: Text::new(),
+            quantity,
+            other_quantity: None,
+            unit_names: |_| {},
+        }
+    }
+
+    /// Kind of error.
+    #[must_use]
+    pub fn kind(&self) -> ParseQuantityErrorKind {
+        self.kind
+    }
+
+    /// Byte range of the token in the parsed string that caused the error.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Unrecognized unit for [`ParseQuantityErrorKind::UnknownUnit`] errors. Without the `std`
+    /// feature long units are truncated, see [`is_truncated`](Self::is_truncated). The full unit
+    /// is always available by slicing the parsed string with [`span`](Self::span).
+    #[must_use]
+    pub fn unit(&self) -> Option<&str> {
+        match self.kind {
+            ParseQuantityErrorKind::UnknownUnit => Some(self.text.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Message of the underlying error encountered while parsing the value for
+    /// [`ParseQuantityErrorKind::ValueParseError`] errors. Without the `std` feature long messages
+    /// are truncated, see [`is_truncated`](Self::is_truncated).
+    #[must_use]
+    pub fn value_error(&self) -> Option<&str> {
+        match self.kind {
+            ParseQuantityErrorKind::ValueParseError => Some(self.text.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Whether the text returned by [`unit`](Self::unit) or [`value_error`](Self::value_error)
+    /// was truncated. Text is only truncated when the `std` feature is not enabled.
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.text.truncated
+    }
+
+    /// Description of the quantity being parsed, e.g. `"length"`.
+    #[must_use]
+    pub fn quantity(&self) -> &'static str {
+        self.quantity
+    }
+
+    /// Description of another quantity of the system when the unrecognized unit is one of its
+    /// units, e.g. `"time"` when parsing `"5 s"` as a length.
+    #[must_use]
+    pub fn other_quantity(&self) -> Option<&'static str> {
+        self.other_quantity
+    }
+
+    /// Units of the quantity that closely match the unrecognized unit, e.g. `km` for `kmh`,
+    /// ordered from closest to furthest. Matches are found by comparing the edit distance of the
+    /// unrecognized unit to the abbreviation, singular, and plural description of every unit of
+    /// the quantity. No suggestions are made when the unrecognized unit was truncated.
+    pub fn suggestions(&self) -> impl Iterator<Item = &'static str> {
+        let mut suggestions: [Option<(usize, &'static str)>; MAX_SUGGESTIONS] =
+            [None; MAX_SUGGESTIONS];
+
+        if let Some(unit) = self.unit().filter(|_| !self.is_truncated()) {
+            let length = unit.chars().count();
+            let threshold = (length / 3 + 1).min(3);
+
+            (self.unit_names)(&mut |name| {
+                let distance = match edit_distance(unit, name) {
+                    Some(distance) if distance <= threshold && distance < length => distance,
+                    _ => return,
+                };
+
+                if suggestions.iter().flatten().any(|&(_, n)| n == name) {
+                    return;
+                }
+
+                // Insert after suggestions with the same or a smaller distance.
+                let mut candidate = Some((distance, name));
+
+                for suggestion in suggestions.iter_mut() {
+                    match (*suggestion, candidate) {
+                        (Some((d, _)), Some((c, _))) if d <= c => {}
+                        (_, Some(_)) => candidate = crate::lib::mem::replace(suggestion, candidate),
+                        (_, None) => break,
+                    }
+                }
+            });
+        }
+
+        suggestions.into_iter().flatten().map(|(_, name)| name)
+    }
+}
+
+impl fmt::Debug for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseQuantityError")
+            .field("kind", &self.kind)
+            .field("span", &self.span)
+            .field("text", &self.text.as_str())
+            .field("truncated", &self.text.truncated)
+            .field("quantity", &self.quantity)
+            .field("other_quantity", &self.other_quantity)
+            .finish()
+    }
+}
+
+impl PartialEq for ParseQuantityError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.span == other.span
+            && self.text == other.text
+            && self.quantity == other.quantity
+            && self.other_quantity == other.other_quantity
+    }
+}
+
+impl Eq for ParseQuantityError {}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ParseQuantityErrorKind::{NoSeparator, UnknownUnit, ValueParseError};
+
+        match self.kind {
+            NoSeparator => write!(f, "no space between quantity and units"),
+            ValueParseError if self.text.as_str().is_empty() => {
+                write!(f, "error parsing unit quantity")
+            }
+            ValueParseError => write!(f, "error parsing unit quantity: {}", self.text),
+            UnknownUnit => {
+                write!(f, "unrecognized unit of measure `{}` for {}", self.text, self.quantity)?;
+
+                if let Some(other_quantity) = self.other_quantity {
+                    return write!(f, "; `{}` is a unit of {}", self.text, other_quantity);
+                }
+
+                for (i, suggestion) in self.suggestions().enumerate() {
+                    let separator = if i == 0 { "; did you mean" } else { " or" };
+
+                    write!(f, "{} `{}`", separator, suggestion)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::error::Error for ParseQuantityError {}
+
+/// Serialize and deserialize a unit by abbreviation, e.g. `"km"`, instead of by identifier, e.g.
+/// `"kilometer"`. Use with `#[serde(with = "uom::str::serde_abbreviation")]` on a field whose
+/// type is a quantity's `Units` enum.
+///
+/// Deserialization accepts the abbreviation, singular, or plural description of a unit.
+#[cfg(feature = "serde")]
+pub mod serde_abbreviation {
+    use crate::lib::fmt::{self, Display, Formatter};
+    use crate::lib::marker::PhantomData;
+    use crate::lib::str::FromStr;
+    use crate::serde::{de, Deserializer, Serializer};
+
+    /// Serializes `unit` as its abbreviation.
+    ///
+    /// # Errors
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<T, S>(unit: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(unit)
+    }
+
+    /// Deserializes a unit from its abbreviation, singular, or plural description.
+    ///
+    /// # Errors
+    /// Returns an error when the string isn't a unit of the quantity or any error produced by
+    /// `deserializer`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        struct Visitor<T>(PhantomData<T>);
+
+        impl<'de, T> de::Visitor<'de> for Visitor<T>
+        where
+            T: FromStr,
+            T::Err: Display,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a unit abbreviation")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                T::from_str(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor(PhantomData))
+    }
+}
+
+/// Serialize and deserialize a quantity together with its unit so that serialized data is
+/// self-describing and independent of the base units of the reader. Every quantity has two helper
+/// modules for use with `#[serde(with = "...")]`:
+///
+///  * `serde_object`, e.g. `uom::si::length::serde_object`, serializes as
+///    `{"value": 5.0, "unit": "km"}`.
+///  * `serde_string`, e.g. `uom::si::length::serde_string`, serializes as `"5 km"`.
+///
+/// The `serialize` function of both modules serializes in the quantity's coherent unit, e.g.
+/// meter, or in the first unit of the quantity when there isn't one. Use `serialize_in` with
+/// `#[serde(serialize_with = "...")]` to serialize in a chosen unit, e.g.
+/// `uom::si::length::serde_string::serialize_in::<kilometer, _, _, _>`.
+///
+/// The `deserialize` function of both modules accepts either representation or a single entry map
+/// from unit to value, e.g. `{"km": 5.0}`, in any unit of the quantity, including unit
+/// expressions, e.g. `"5 km/h"`, and converts into the base units of the target quantity. A bare
+/// number is interpreted as a value in the base units of the target quantity. The `strict`
+/// sub-module of both modules rejects bare numbers. Deserialization requires a self-describing
+/// format, e.g. JSON. See [`Lenient`] to deserialize a quantity field without an attribute.
+///
+#[cfg_attr(all(feature = "si", feature = "f64", feature = "serde"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "f64", feature = "serde")), doc = " ```rust,ignore")]
+/// # use uom::si::f64::*;
+/// # use uom::si::length::{self, kilometer, meter};
+/// let l = Length::new::<kilometer>(5.0);
+/// let mut json = Vec::new();
+///
+/// length::serde_object::serialize_in::<kilometer, _, _, _>(&l,
+///     &mut serde_json::Serializer::new(&mut json)).unwrap();
+/// assert_eq!(r#"{"value":5.0,"unit":"km"}"#, String::from_utf8(json).unwrap());
+///
+/// let l: Length = length::serde_string::deserialize(
+///     &mut serde_json::Deserializer::from_str(r#""5 km""#)).unwrap();
+/// assert_eq!(5_000.0, l.get::<meter>());
+///
+/// let l: Result<Length, _> = length::serde_string::strict::deserialize(
+///     &mut serde_json::Deserializer::from_str("5.0"));
+/// assert!(l.is_err());
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_quantity {
+    use super::{ParseQuantityError, MAX_VALUE};
+    use crate::lib::fmt::{self, Display, Formatter};
+    use crate::lib::marker::PhantomData;
+    use crate::lib::str::FromStr;
+    #[cfg(feature = "schemars")]
+    use crate::schemars::Schema;
+    use crate::serde::de::{self, IntoDeserializer, Unexpected};
+    use crate::serde::ser::SerializeStruct;
+    use crate::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    #[cfg(feature = "schemars")]
+    use alloc::{format, string::String, vec::Vec};
+
+    const FIELDS: &[&str] = &["value", "unit"];
+
+    /// Creates a quantity from a value and the abbreviation, singular, or plural description of
+    /// a unit. Implemented for every quantity.
+    #[doc(hidden)]
+    pub trait FromValueUnit: Sized {
+        /// Underlying storage type of the quantity.
+        type Value;
+
+        /// Creates a quantity from the given value and unit.
+        ///
+        /// # Errors
+        /// Returns a [`ParseQuantityError`] when the unit isn't recognized.
+        fn from_value_unit(value: Self::Value, unit: &str) -> Result<Self, ParseQuantityError>;
+
+        /// Quantity description.
+        #[cfg(feature = "schemars")]
+        const DESCRIPTION: &'static str;
+
+        /// Abbreviations of the units of the quantity.
+        #[cfg(feature = "schemars")]
+        const ABBREVIATIONS: &'static [&'static str];
+    }
+
+    /// Serializes `value` and `unit` as `{"value": value, "unit": unit}`.
+    ///
+    /// # Errors
+    /// Returns any error produced by `serializer`.
+    #[doc(hidden)]
+    pub fn serialize_object<V, S>(value: &V, unit: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Quantity", 2)?;
+
+        state.serialize_field("value", value)?;
+        state.serialize_field("unit", unit)?;
+        state.end()
+    }
+
+    /// Serializes `value` and `unit` as `"value unit"`.
+    ///
+    /// # Errors
+    /// Returns any error produced by `serializer`.
+    #[doc(hidden)]
+    pub fn serialize_string<V, S>(value: &V, unit: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{} {}", value, unit))
+    }
+
+    /// Adds the quantity description and the abbreviation of the base units, `units`, to the JSON
+    /// Schema of a bare value in base units.
+    #[cfg(feature = "schemars")]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn value_schema(
+        mut schema: Schema,
+        quantity: Option<&str>,
+        units: &[(&str, i32)],
+    ) -> Schema {
+        struct Units<'a>(&'a [(&'a str, i32)]);
+
+        impl Display for Units<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                crate::fmt::write_units(f, self.0, crate::fmt::Notation::Ascii)
+            }
+        }
+
+        let unit = format!("{}", Units(units));
+        let description = match (quantity, unit.is_empty()) {
+            (Some(quantity), false) => format!("{} in {}", quantity, unit),
+            (Some(quantity), true) => quantity.into(),
+            (None, false) => format!("value in {}", unit),
+            (None, true) => return schema,
+        };
+
+        let _ = schema.insert("description".into(), description.into());
+
+        if !unit.is_empty() {
+            let _ = schema.insert("x-unit".into(), unit.into());
+        }
+
+        schema
+    }
+
+    /// Creates the JSON Schema of `{"value": value, "unit": unit}` where `unit` is one of the
+    /// given abbreviations.
+    #[cfg(feature = "schemars")]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn object_schema(quantity: &str, abbreviations: &[&str]) -> Schema {
+        crate::schemars::json_schema!({
+            "description": format!("{} with a unit", quantity),
+            "type": "object",
+            "properties": {
+                "value": { "type": "number" },
+                "unit": { "type": "string", "enum": unique(abbreviations) },
+            },
+            "required": FIELDS,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Creates the JSON Schema of `{unit: value}` where `unit` is one of the given abbreviations.
+    #[cfg(feature = "schemars")]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn map_schema(quantity: &str, abbreviations: &[&str]) -> Schema {
+        crate::schemars::json_schema!({
+            "description": format!("{} as a map from unit to value", quantity),
+            "type": "object",
+            "propertyNames": { "enum": unique(abbreviations) },
+            "additionalProperties": { "type": "number" },
+            "minProperties": 1,
+            "maxProperties": 1,
+        })
+    }
+
+    /// Creates the JSON Schema of `"value unit"` where `unit` is one of the given abbreviations.
+    #[cfg(feature = "schemars")]
+    #[doc(hidden)]
+    #[must_use]
+    pub fn string_schema(quantity: &str, abbreviations: &[&str]) -> Schema {
+        let mut pattern = String::from(r"^\S+ (?:");
+
+        for (i, abbreviation) in unique(abbreviations).iter().enumerate() {
+            if i > 0 {
+                pattern.push('|');
+            }
+
+            for c in abbreviation.chars() {
+                if r"\^$.|?*+()[]{}/".contains(c) {
+                    pattern.push('\\');
+                }
+
+                pattern.push(c);
+            }
+        }
+
+        pattern.push_str(")$");
+
+        crate::schemars::json_schema!({
+            "description": format!("{} with a unit, e.g. \"5 {}\"", quantity,
+                abbreviations.first().copied().unwrap_or_default()),
+            "type": "string",
+            "pattern": pattern,
+        })
+    }
+
+    /// Returns the given abbreviations without duplicates.
+    #[cfg(feature = "schemars")]
+    fn unique<'a>(abbreviations: &[&'a str]) -> Vec<&'a str> {
+        let mut unique = Vec::with_capacity(abbreviations.len());
+
+        for &abbreviation in abbreviations {
+            if !unique.contains(&abbreviation) {
+                unique.push(abbreviation);
+            }
+        }
+
+        unique
+    }
+
+    /// Deserializes a quantity from `{"value": value, "unit": unit}`, `{unit: value}`,
+    /// `"value unit"`, or, unless `strict`, a bare number in base units.
+    ///
+    /// # Errors
+    /// Returns an error when the input isn't a quantity or any error produced by `deserializer`.
+    #[doc(hidden)]
+    pub fn deserialize<'de, T, De>(deserializer: De, strict: bool) -> Result<T, De::Error>
+    where
+        T: FromValueUnit + FromStr<Err = ParseQuantityError> + Deserialize<'de>,
+        T::Value: Deserialize<'de>,
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Visitor { strict, quantity: PhantomData })
+    }
+
+    struct Visitor<T> {
+        strict: bool,
+        quantity: PhantomData<fn() -> T>,
+    }
+
+    impl<'de, T> Visitor<T>
+    where
+        T: FromValueUnit + FromStr<Err = ParseQuantityError> + Deserialize<'de>,
+        T::Value: Deserialize<'de>,
+    {
+        /// Deserializes a bare value in base units unless `strict`.
+        fn bare<E, De>(&self, value: De, unexpected: Unexpected<'_>) -> Result<T, E>
+        where
+            E: de::Error,
+            De: Deserializer<'de, Error = E>,
+        {
+            if self.strict {
+                return Err(E::invalid_type(unexpected, self));
+            }
+
+            T::deserialize(value)
+        }
+    }
+
+    impl<'de, T> de::Visitor<'de> for Visitor<T>
+    where
+        T: FromValueUnit + FromStr<Err = ParseQuantityError> + Deserialize<'de>,
+        T::Value: Deserialize<'de>,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("a quantity with a unit, e.g. `{\"value\": 5.0, \"unit\": \"km\"}` or ")?;
+            f.write_str("`{\"km\": 5.0}`, or `\"5 km\"`")?;
+
+            if self.strict {
+                Ok(())
+            } else {
+                f.write_str(", or a number")
+            }
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            T::from_str(v).map_err(E::custom)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut value: Option<T::Value> = None;
+            let mut unit: Option<Name> = None;
+            let mut key = map.next_key::<Name>()?;
+
+            if let Some(unit) = key.as_ref().filter(|key| !FIELDS.contains(&key.as_str())) {
+                let value = map.next_value()?;
+
+                if map.next_key::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(2, &self));
+                }
+
+                return T::from_value_unit(value, unit.as_str()).map_err(de::Error::custom);
+            }
+
+            while let Some(name) = key {
+                match name.as_str() {
+                    "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                    "unit" if unit.is_some() => return Err(de::Error::duplicate_field("unit")),
+                    "value" => value = Some(map.next_value()?),
+                    "unit" => unit = Some(map.next_value()?),
+                    other => return Err(de::Error::unknown_field(other, FIELDS)),
+                }
+
+                key = map.next_key()?;
+            }
+
+            let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+            let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+
+            T::from_value_unit(value, unit.as_str()).map_err(de::Error::custom)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            self.bare(de::value::SeqAccessDeserializer::new(seq), Unexpected::Seq)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.bare(v.into_deserializer(), Unexpected::Signed(v))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.bare(v.into_deserializer(), Unexpected::Unsigned(v))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.bare(v.into_deserializer(), Unexpected::Float(v))
+        }
+    }
+
+    /// Field name or unit deserialized into a fixed capacity buffer.
+    struct Name {
+        bytes: [u8; MAX_VALUE],
+        len: usize,
+    }
+
+    impl Name {
+        fn as_str(&self) -> &str {
+            crate::lib::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Name {
+        fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+        where
+            De: Deserializer<'de>,
+        {
+            struct Visitor;
+
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Name;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, "a string of at most {} bytes", MAX_VALUE)
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    let mut name = Name { bytes: [0; MAX_VALUE], len: v.len() };
+
+                    name.bytes
+                        .get_mut(..v.len())
+                        .ok_or_else(|| E::invalid_length(v.len(), &self))?
+                        .copy_from_slice(v.as_bytes());
+
+                    Ok(name)
+                }
+            }
+
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+}
+
+/// Adapter to deserialize a quantity written by a human, e.g. in a configuration file, in any
+/// unit of the quantity instead of as a bare number in base units.
+///
+/// Deserialization accepts a string parsed with the quantity's `FromStr` implementation, e.g.
+/// `"12.5 psi"`, a single entry map from unit to value, e.g. `{ kPa = 86.1 }` in TOML, an object
+/// with a value and a unit, e.g. `{"value": 86.1, "unit": "kPa"}`, or a bare number in base units.
+/// The value is converted into the base units of the quantity. Serialization is the same as for
+/// the quantity. Deserialization requires a self-describing format, e.g. JSON, TOML, or YAML. See
+/// [`serde_quantity`] for per-field helper modules.
+///
+#[cfg_attr(all(feature = "si", feature = "f64", feature = "serde"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "f64", feature = "serde")), doc = " ```rust,ignore")]
+/// # use uom::si::f64::*;
+/// # use uom::si::pressure::kilopascal;
+/// use uom::str::Lenient;
+///
+/// let p: Lenient<Pressure> = serde_json::from_str(r#""86.1 kPa""#).unwrap();
+/// assert_eq!(86.1, p.get::<kilopascal>());
+///
+/// let p: Lenient<Pressure> = serde_json::from_str(r#"{"kPa": 86.1}"#).unwrap();
+/// assert_eq!(86.1, p.0.get::<kilopascal>());
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lenient<Q>(pub Q);
+
+#[cfg(feature = "serde")]
+impl<Q> Lenient<Q> {
+    /// Returns the quantity.
+    #[must_use]
+    pub fn into_inner(self) -> Q {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Q> From<Q> for Lenient<Q> {
+    fn from(quantity: Q) -> Self {
+        Lenient(quantity)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Q> crate::lib::ops::Deref for Lenient<Q> {
+    type Target = Q;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Q> crate::lib::ops::DerefMut for Lenient<Q> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Q> serde::Serialize for Lenient<Q>
+where
+    Q: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Q> serde::Deserialize<'de> for Lenient<Q>
+where
+    Q: serde_quantity::FromValueUnit
+        + crate::lib::str::FromStr<Err = ParseQuantityError>
+        + serde::Deserialize<'de>,
+    Q::Value: serde::Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        serde_quantity::deserialize(deserializer, false).map(Lenient)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<Q> schemars::JsonSchema for Lenient<Q>
+where
+    Q: serde_quantity::FromValueUnit + schemars::JsonSchema,
+{
+    fn schema_name() -> crate::lib::borrow::Cow<'static, str> {
+        alloc::format!("Lenient{}", Q::schema_name()).into()
+    }
+
+    fn schema_id() -> crate::lib::borrow::Cow<'static, str> {
+        alloc::format!("uom::str::Lenient<{}>", Q::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": Q::DESCRIPTION,
+            "anyOf": [
+                serde_quantity::string_schema(Q::DESCRIPTION, Q::ABBREVIATIONS),
+                serde_quantity::map_schema(Q::DESCRIPTION, Q::ABBREVIATIONS),
+                serde_quantity::object_schema(Q::DESCRIPTION, Q::ABBREVIATIONS),
+                generator.subschema_for::<Q>(),
+            ],
+        })
+    }
+}
+
+/// Maximum length in bytes of text stored in a [`ParseQuantityError`] when the `std` feature is
+/// not enabled.
+#[cfg(not(feature = "std"))]
+const MAX_TEXT: usize = 40;
+
+/// String stored in a [`ParseQuantityError`]. Text is stored in full when the `std` feature is
+/// enabled. Otherwise text exceeding a fixed capacity is truncated at a character boundary and
+/// marked as truncated.
+#[derive(Clone, Eq, PartialEq)]
+struct Text {
+    #[cfg(feature = "std")]
+    string: String,
+    #[cfg(not(feature = "std"))]
+    bytes: [u8; MAX_TEXT],
+    #[cfg(not(feature = "std"))]
+    len: u8,
+    truncated: bool,
+}
+
+impl Text {
+    fn new() -> Self {
+        Text {
+            #[cfg(feature = "std")]
+            string: String::new(),
+            #[cfg(not(feature = "std"))]
+            bytes: [0; MAX_TEXT],
+            #[cfg(not(feature = "std"))]
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn as_str(&self) -> &str {
+        crate::lib::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for Text {
+    #[cfg(feature = "std")]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.string.push_str(s);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let len = self.len as usize;
+            let end = len + c.len_utf8();
+
+            if end > MAX_TEXT {
+                self.truncated = true;
+
+                return Err(fmt::Error);
+            }
+
+            let _ = c.encode_utf8(&mut self.bytes[len..end]);
+            self.len = end as u8;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Text {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())?;
+
+        if self.truncated {
+            f.write_str("…")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Optimal string alignment distance between two strings, i.e. the number of character
+/// insertions, deletions, substitutions, and transpositions of adjacent characters needed to
+/// change one string into the other. Returns `None` if either string is too long.
+fn edit_distance(a: &str, b: &str) -> Option<usize> {
+    const MAX: usize = 64;
+
+    let mut a_chars = ['\0'; MAX];
+    let mut b_chars = ['\0'; MAX];
+    let a_len = collect_chars(a, &mut a_chars)?;
+    let b_len = collect_chars(b, &mut b_chars)?;
+    let (a, b) = (&a_chars[..a_len], &b_chars[..b_len]);
+
+    // Rows of the distance matrix for the two previous and the current character of `a`.
+    let mut previous2 = [0; MAX + 1];
+    let mut previous = [0; MAX + 1];
+    let mut current = [0; MAX + 1];
+
+    for (j, d) in previous.iter_mut().enumerate().take(b.len() + 1) {
+        *d = j;
+    }
+
+    for i in 1..=a.len() {
+        current[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(previous2[j - 2] + 1);
+            }
+        }
+
+        previous2 = previous;
+        previous = current;
+    }
+
+    Some(previous[b.len()])
+}
+
+/// Collects the characters of `s` into `chars`. Returns the number of characters or `None` if `s`
+/// has too many characters.
+fn collect_chars(s: &str, chars: &mut [char]) -> Option<usize> {
+    let mut len = 0;
+
+    for c in s.chars() {
+        *chars.get_mut(len)? = c;
+        len += 1;
+    }
+
+    Some(len)
+}
+
+/// Options to control how a string is parsed into a `Quantity`.
+///
+/// The default options match the behavior of `FromStr`: the value and unit are separated by a
+/// single space and the entire remainder of the string must be a unit.
+///
+#[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+#[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+/// # use uom::si::f32::*;
+/// # use uom::si::length::{foot, kilometer};
+/// # use uom::str::{ParseOptions, Separator, Trailing};
+/// let l = Length::from_str_with("5 kft", ParseOptions::new().prefixes(true)).unwrap();
+///
+/// assert_eq!(5.0E3, l.get::<foot>());
+///
+/// let options = ParseOptions::lenient().trailing(Trailing::Ignore);
+///
+/// assert_eq!(5.0, Length::from_str_with("5km", options).unwrap().get::<kilometer>());
+/// assert_eq!(5.0, Length::from_str_with("5\u{a0}km", options).unwrap().get::<kilometer>());
+/// assert_eq!(-5.0, Length::from_str_with("km −5", options).unwrap().get::<kilometer>());
+/// assert_eq!(5.0, Length::from_str_with("5 km;", options).unwrap().get::<kilometer>());
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    /// Resolve units that aren't defined with a prefix, e.g. `kft` or `µin`, by splitting a
+    /// leading [SI prefix][prefix] from a known unit. Binary prefixes, e.g. `Ki`, are only
+    /// recognized for quantities that define binary prefixed units. Units matching exactly are
+    /// always preferred so that `min` or `Pa` are never read as prefixed units.
+    ///
+    /// [prefix]: https://jcgm.bipm.org/vim/en/1.26.html
+    pub prefixes: bool,
+
+    /// Separator between the value and the unit.
+    pub separator: Separator,
+
+    /// Accept a leading `+`, `-`, or `−` (U+2212 minus sign) sign that may be separated from the
+    /// value by whitespace, e.g. `- 5 m` or `−5 m`.
+    pub sign: bool,
+
+    /// Accept the unit before the value, e.g. `km 5`, in addition to the value before the unit.
+    pub unit_first: bool,
+
+    /// Handling of characters following the unit.
+    pub trailing: Trailing,
+}
+
+/// Separator between the value and the unit of a quantity being parsed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Separator {
+    /// A single space, e.g. `5 km`.
+    #[default]
+    Space,
+
+    /// Any amount of whitespace, including non-breaking and thin spaces, e.g. `5  km` or
+    /// `5\u{a0}km`.
+    Whitespace,
+
+    /// Any amount of whitespace or no separator when the value is a decimal number, e.g. `5km`,
+    /// `1.5e3m`, or `10°C`.
+    Optional,
+}
+
+/// Handling of characters following the unit of a quantity being parsed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Trailing {
+    /// The remainder of the string after the value must be a unit.
+    #[default]
+    Reject,
+
+    /// Ignore characters after the longest leading part of the remainder, ending at whitespace,
+    /// `,`, or `;`, that is a unit, e.g. `5 km; measured` is parsed as `5 km`.
+    Ignore,
+}
+
+impl ParseOptions {
+    /// Creates options that match the behavior of `FromStr`.
+    #[must_use]
+    pub const fn new() -> Self {
+        ParseOptions {
+            prefixes: false,
+            separator: Separator::Space,
+            sign: false,
+            unit_first: false,
+            trailing: Trailing::Reject,
+        }
+    }
+
+    /// Creates options that accept any whitespace or no separator, a leading sign, and the unit
+    /// before the value. Prefixes and trailing characters are handled as in
+    /// [`ParseOptions::new`].
+    #[must_use]
+    pub const fn lenient() -> Self {
+        Self::new().separator(Separator::Optional).sign(true).unit_first(true)
+    }
+
+    /// Sets whether units may be resolved by splitting a leading SI prefix. See
+    /// [`ParseOptions::prefixes`](#structfield.prefixes).
+    #[must_use]
+    pub const fn prefixes(mut self, prefixes: bool) -> Self {
+        self.prefixes = prefixes;
+
+        self
+    }
+
+    /// Sets the separator between the value and the unit.
+    #[must_use]
+    pub const fn separator(mut self, separator: Separator) -> Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// Sets whether a leading sign separated from the value is accepted. See
+    /// [`ParseOptions::sign`](#structfield.sign).
+    #[must_use]
+    pub const fn sign(mut self, sign: bool) -> Self {
+        self.sign = sign;
+
+        self
+    }
+
+    /// Sets whether the unit may come before the value.
+    #[must_use]
+    pub const fn unit_first(mut self, unit_first: bool) -> Self {
+        self.unit_first = unit_first;
+
+        self
+    }
+
+    /// Sets the handling of characters following the unit.
+    #[must_use]
+    pub const fn trailing(mut self, trailing: Trailing) -> Self {
+        self.trailing = trailing;
+
+        self
+    }
+}
+
+/// Maximum number of ways a string is split into a value and a unit. See [`layouts`].
+const MAX_LAYOUTS: usize = 4;
+
+/// Maximum length in bytes of a value with a separated sign. See [`Layout::value`].
+#[doc(hidden)]
+pub const MAX_VALUE: usize = 128;
+
+/// Split of a string being parsed into a value and a unit.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Layout<'a> {
+    value: &'a str,
+    negative: bool,
+    unit: &'a str,
+}
+
+impl<'a> Layout<'a> {
+    /// Value of the layout. Negative values with a separated sign are written into `buffer`.
+    /// Returns `None` if the value doesn't fit into `buffer`.
+    #[must_use]
+    pub fn value<'b>(&self, buffer: &'b mut [u8; MAX_VALUE]) -> Option<&'b str>
+    where
+        'a: 'b,
+    {
+        if !self.negative {
+            return Some(self.value);
+        }
+
+        let end = self.value.len() + 1;
+
+        if end > MAX_VALUE {
+            return None;
+        }
+
+        buffer[0] = b'-';
+        buffer[1..end].copy_from_slice(self.value.as_bytes());
+
+        crate::lib::str::from_utf8(&buffer[..end]).ok()
+    }
+
+    /// Value of the layout without any separated sign.
+    #[must_use]
+    pub fn raw_value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Unit of the layout.
+    #[must_use]
+    pub fn unit(&self) -> &'a str {
+        self.unit
+    }
+
+    /// Unit followed by, when trailing characters are ignored, successively shorter leading
+    /// parts of the unit.
+    pub fn units(&self, trailing: Trailing) -> impl Iterator<Item = &'a str> {
+        let unit = self.unit;
+        let ignore = trailing == Trailing::Ignore;
+
+        crate::lib::iter::once(unit).chain(
+            unit.char_indices()
+                .rev()
+                .filter(move |&(_, c)| ignore && (c.is_whitespace() || matches!(c, ',' | ';')))
+                .map(move |(i, _)| unit[..i].trim_end())
+                .filter(|unit| !unit.is_empty()),
+        )
+    }
+}
+
+/// Splits a string being parsed into possible values and units based on the given options.
+/// Layouts are ordered from most to least preferred.
+#[doc(hidden)]
+#[must_use]
+pub fn layouts<'a>(s: &'a str, options: &ParseOptions) -> [Option<Layout<'a>>; MAX_LAYOUTS] {
+    let mut layouts = [None; MAX_LAYOUTS];
+    let t = match options.separator {
+        Separator::Space => s,
+        Separator::Whitespace | Separator::Optional => s.trim(),
+    };
+
+    // Value before the unit.
+    let (negative, v) = sign(t, options.sign);
+    let split = match options.separator {
+        Separator::Space => v.split_once(' '),
+        Separator::Whitespace | Separator::Optional => {
+            v.find(char::is_whitespace).map(|i| (&v[..i], &v[i..]))
+        }
+    };
+
+    if let Some((value, unit)) = split {
+        layouts[0] = Some(Layout { value, negative, unit: unit.trim() });
+    }
+
+    if options.separator == Separator::Optional {
+        let end = number_len(v);
+        let token = v.find(char::is_whitespace).unwrap_or(v.len());
+
+        if end > 0 && end < token {
+            layouts[1] = Some(Layout { value: &v[..end], negative, unit: v[end..].trim() });
+        }
+    }
+
+    // Unit before the value.
+    if options.unit_first {
+        let split = match options.separator {
+            Separator::Space => t.rfind(' '),
+            Separator::Whitespace | Separator::Optional => t.rfind(char::is_whitespace),
+        };
+
+        if let Some(i) = split {
+            let (negative, value) = sign(t[i..].trim_start(), options.sign);
+
+            layouts[2] = Some(Layout { value, negative, unit: t[..i].trim() });
+        }
+
+        if options.separator == Separator::Optional {
+            let split = t.char_indices().skip(1).find_map(|(i, _)| {
+                let (negative, value) = sign(&t[i..], options.sign);
+
+                if !value.is_empty() && number_len(value) == value.len() {
+                    Some((i, negative, value))
+                } else {
+                    None
+                }
+            });
+
+            if let Some((i, negative, value)) = split {
+                layouts[3] = Some(Layout { value, negative, unit: t[..i].trim() });
+            }
+        }
+    }
+
+    layouts
+}
+
+/// Parses a string into a value and unit using the layouts of the given options. `resolve` is
+/// called with the value and unit of each layout, see [`Layout::units`], and returns `Ok(None)`
+/// when the unit isn't recognized. `unknown_unit` creates the error for an unrecognized unit.
+///
+/// When no layout resolves, the error of the first layout is returned unless it is a value parse
+/// error, in which case the error of a later layout with a valid value takes precedence.
+#[doc(hidden)]
+pub fn parse_layouts<'a, V, T, E>(
+    s: &'a str,
+    options: ParseOptions,
+    quantity: &'static str,
+    resolve: &mut dyn FnMut(V, &'a str) -> Result<Option<T>, E>,
+    unknown_unit: &dyn Fn(&'a str) -> ParseQuantityError,
+) -> Result<T, E>
+where
+    V: crate::lib::str::FromStr + Clone,
+    V::Err: Display,
+    E: From<ParseQuantityError>,
+{
+    let mut error: Option<E> = None;
+    let mut value_error = false;
+
+    for layout in layouts(s, &options).iter().flatten() {
+        let mut buffer = [0; MAX_VALUE];
+        let value = match layout.value(&mut buffer).map(str::parse::<V>) {
+            Some(Ok(value)) => value,
+            e => {
+                if error.is_none() {
+                    let e: &dyn Display = match &e {
+                        Some(Err(e)) => e,
+                        _ => &"value is too long",
+                    };
+
+                    error = Some(
+                        ParseQuantityError::value_parse_error(
+                            quantity,
+                            span(s, layout.raw_value()),
+                            e,
+                        )
+                        .into(),
+                    );
+                    value_error = true;
+                }
+
+                continue;
+            }
+        };
+        let mut layout_error = None;
+
+        for unit in layout.units(options.trailing) {
+            match resolve(value.clone(), unit) {
+                Ok(Some(t)) => return Ok(t),
+                Ok(None) => {}
+                Err(e) => {
+                    layout_error = Some(e);
+
+                    break;
+                }
+            }
+        }
+
+        if error.is_none() || value_error {
+            error = Some(layout_error.unwrap_or_else(|| unknown_unit(layout.unit()).into()));
+            value_error = false;
+        }
+    }
+
+    Err(error.unwrap_or_else(|| ParseQuantityError::no_separator(quantity, 0..s.len()).into()))
+}
+
+/// Splits a sum of quantities, e.g. `5 ft 3 in` or `30°15′`, into values and units. A new value
+/// starts at a number at the start of the string or following whitespace or a character that
+/// isn't part of a unit expression, e.g. `°`. Yields the byte range of a value without a unit as
+/// an error.
+#[doc(hidden)]
+pub fn terms(s: &str) -> impl Iterator<Item = Result<(&str, &str), Range<usize>>> {
+    let mut rest = s.trim_start();
+
+    crate::lib::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        // Values that aren't decimal numbers, e.g. the ratio `1/2`, must be followed by whitespace.
+        let token = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let value_len = match number_len(rest) {
+            0 => token,
+            len if rest[len..].starts_with('/') => token,
+            len => len,
+        };
+        let (value, tail) = rest.split_at(value_len);
+        let tail = tail.trim_start();
+        let unit_len = tail
+            .char_indices()
+            .find(|&(i, _)| {
+                i > 0
+                    && number_len(&tail[i..]) > 0
+                    && tail[..i].chars().next_back().map_or(false, is_term_delimiter)
+            })
+            .map_or(tail.len(), |(i, _)| i);
+        let (unit, tail) = tail.split_at(unit_len);
+
+        rest = tail.trim_start();
+
+        match unit.trim_end() {
+            "" => Some(Err(span(s, value))),
+            unit => Some(Ok((value, unit))),
+        }
+    })
+}
+
+/// Returns `true` if a value in a sum of quantities may follow the character.
+fn is_term_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || !(c.is_alphanumeric()
+            || matches!(c, '^' | '(' | '/' | '*' | '·' | '⋅' | '-' | '+' | '.'))
+}
+
+/// Removes a leading sign and any following whitespace from `s` when `enabled` is `true`. Returns
+/// whether the sign is negative and the remainder of the string.
+fn sign(s: &str, enabled: bool) -> (bool, &str) {
+    if !enabled {
+        return (false, s);
+    }
+
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some('+') => (false, chars.as_str().trim_start()),
+        Some('-' | '−') => (true, chars.as_str().trim_start()),
+        _ => (false, s),
+    }
+}
+
+/// Returns the length in bytes of the decimal number, e.g. `-1.5e3`, at the start of `s` or `0`
+/// if `s` doesn't start with a number.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while bytes.get(i).map_or(false, u8::is_ascii_digit) {
+            i += 1;
+        }
+
+        i
+    };
+    let start = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let mut end = digits(start);
+    let mut mantissa = end - start;
+
+    if bytes.get(end) == Some(&b'.') {
+        let fraction = digits(end + 1);
+
+        mantissa += fraction - end - 1;
+        end = fraction;
+    }
+
+    if mantissa == 0 {
+        return 0;
+    }
+
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = end + 1 + usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent = digits(sign);
+
+        if exponent > sign {
+            end = exponent;
+        }
+    }
+
+    end
+}
+
+/// Byte range of `sub`, a sub-slice of `s`, within `s`.
+#[doc(hidden)]
+#[must_use]
+pub fn span(s: &str, sub: &str) -> Range<usize> {
+    let start = (sub.as_ptr() as usize).saturating_sub(s.as_ptr() as usize);
+
+    start..start + sub.len()
+}
+
+/// Prefix recognized when resolving prefixed units.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Prefix {
+    /// Prefix symbol combined with unit abbreviations.
+    pub symbol: &'static str,
+
+    /// Prefix name combined with unit singular and plural descriptions.
+    pub name: &'static str,
+
+    /// Prefix factor.
+    pub factor: f64,
+
+    /// Exact prefix factor.
+    pub exact: ExactFactor,
+
+    /// `true` for binary prefixes.
+    pub binary: bool,
+}
+
+/// SI and binary prefixes matching the `prefix!` macro.
+#[doc(hidden)]
+#[rustfmt::skip]
+pub static PREFIXES: &[Prefix] = &[
+    Prefix { symbol: "Y", name: "yotta", factor: 1.0_E24, exact: ExactFactor::pow10(24), binary: false },
+    Prefix { symbol: "Z", name: "zetta", factor: 1.0_E21, exact: ExactFactor::pow10(21), binary: false },
+    Prefix { symbol: "E", name: "exa", factor: 1.0_E18, exact: ExactFactor::pow10(18), binary: false },
+    Prefix { symbol: "P", name: "peta", factor: 1.0_E15, exact: ExactFactor::pow10(15), binary: false },
+    Prefix { symbol: "T", name: "tera", factor: 1.0_E12, exact: ExactFactor::pow10(12), binary: false },
+    Prefix { symbol: "G", name: "giga", factor: 1.0_E9, exact: ExactFactor::pow10(9), binary: false },
+    Prefix { symbol: "M", name: "mega", factor: 1.0_E6, exact: ExactFactor::pow10(6), binary: false },
+    Prefix { symbol: "k", name: "kilo", factor: 1.0_E3, exact: ExactFactor::pow10(3), binary: false },
+    Prefix { symbol: "h", name: "hecto", factor: 1.0_E2, exact: ExactFactor::pow10(2), binary: false },
+    Prefix { symbol: "da", name: "deca", factor: 1.0_E1, exact: ExactFactor::pow10(1), binary: false },
+    Prefix { symbol: "da", name: "deka", factor: 1.0_E1, exact: ExactFactor::pow10(1), binary: false },
+    Prefix { symbol: "d", name: "deci", factor: 1.0_E-1, exact: ExactFactor::pow10(-1), binary: false },
+    Prefix { symbol: "c", name: "centi", factor: 1.0_E-2, exact: ExactFactor::pow10(-2), binary: false },
+    Prefix { symbol: "m", name: "milli", factor: 1.0_E-3, exact: ExactFactor::pow10(-3), binary: false },
+    Prefix { symbol: "µ", name: "micro", factor: 1.0_E-6, exact: ExactFactor::pow10(-6), binary: false },
+    Prefix { symbol: "μ", name: "micro", factor: 1.0_E-6, exact: ExactFactor::pow10(-6), binary: false },
+    Prefix { symbol: "u", name: "micro", factor: 1.0_E-6, exact: ExactFactor::pow10(-6), binary: false },
+    Prefix { symbol: "n", name: "nano", factor: 1.0_E-9, exact: ExactFactor::pow10(-9), binary: false },
+    Prefix { symbol: "p", name: "pico", factor: 1.0_E-12, exact: ExactFactor::pow10(-12), binary: false },
+    Prefix { symbol: "f", name: "femto", factor: 1.0_E-15, exact: ExactFactor::pow10(-15), binary: false },
+    Prefix { symbol: "a", name: "atto", factor: 1.0_E-18, exact: ExactFactor::pow10(-18), binary: false },
+    Prefix { symbol: "z", name: "zepto", factor: 1.0_E-21, exact: ExactFactor::pow10(-21), binary: false },
+    Prefix { symbol: "y", name: "yocto", factor: 1.0_E-24, exact: ExactFactor::pow10(-24), binary: false },
+    Prefix { symbol: "Yi", name: "yobi", factor: 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 80, 1), binary: true },
+    Prefix { symbol: "Zi", name: "zebi", factor: 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 70, 1), binary: true },
+    Prefix { symbol: "Ei", name: "exbi", factor: 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 60, 1), binary: true },
+    Prefix { symbol: "Pi", name: "pebi", factor: 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 50, 1), binary: true },
+    Prefix { symbol: "Ti", name: "tebi", factor: 1024.0 * 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 40, 1), binary: true },
+    Prefix { symbol: "Gi", name: "gibi", factor: 1024.0 * 1024.0 * 1024.0, exact: ExactFactor::new(1 << 30, 1), binary: true },
+    Prefix { symbol: "Mi", name: "mebi", factor: 1024.0 * 1024.0, exact: ExactFactor::new(1 << 20, 1), binary: true },
+    Prefix { symbol: "Ki", name: "kibi", factor: 1024.0, exact: ExactFactor::new(1 << 10, 1), binary: true },
+];
+
+/// Returns `true` if the unit name in a unit expression matches the given unit abbreviation or
+/// description. Whitespace and multiplication dots in the unit name are ignored so that compact
+/// forms such as `kWh` match `kW · h`.
+#[doc(hidden)]
+#[must_use]
+pub fn unit_name_eq(name: &str, unit: &str) -> bool {
+    name == unit
+        || (unit.contains(is_compact_separator)
+            && name.chars().eq(unit.chars().filter(|&c| !is_compact_separator(c))))
+}
+
+/// Returns `true` for characters ignored by [`unit_name_eq`].
+fn is_compact_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '·' | '⋅')
+}
+
+/// Maximum number of units in a unit expression.
+const MAX_UNITS: usize = 16;
+
+/// Maximum nesting depth of parentheses in a unit expression.
+const MAX_DEPTH: usize = 16;
+
+/// Maximum number of candidates considered when a unit in a unit expression is ambiguous, e.g. `h`
+/// is both the abbreviation of `hour` and `planck_constant`.
+const MAX_CANDIDATES: usize = 4;
+
+/// Unit expression, e.g. `kg·m²/s²`, parsed into a product of units raised to integer powers.
+///
+/// The grammar supports multiplication (`*`, `·`, `⋅`, or whitespace), division (`/`), integer
+/// exponents (`^2`, `^-1`, `^(-1)`, or superscripts such as `²` and `⁻¹`), and parentheses.
+/// Operators are evaluated from left to right so `J/kg·K` is equivalent to `J·K/kg` while
+/// `J/(kg·K)` divides by both units. `1` can be used as a dimensionless numerator, e.g. `1/s`.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct UnitExpression<'a> {
+    units: [(&'a str, i32); MAX_UNITS],
+    len: usize,
+}
+
+/// Callback invoked with the coefficient, dimension exponents, and kind of a unit matching a unit
+/// name in a unit expression.
+#[doc(hidden)]
+pub type UnitCandidate<'a, const N: usize> = dyn FnMut(f64, [i32; N], TypeId) + 'a;
+
+/// Candidate unit for a unit in a unit expression.
+#[derive(Clone, Copy)]
+struct Candidate<const N: usize> {
+    index: usize,
+    coefficient: f64,
+    exponents: [i32; N],
+    kind: TypeId,
+}
+
+/// Unit expression resolved to units of a system of quantities by
+/// [`UnitExpression::coefficient`] or [`UnitExpression::resolve`].
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct ResolvedUnitExpression<'a, const N: usize> {
+    /// Conversion coefficient of the expression to the base units of the system as an `f64`.
+    pub coefficient: f64,
+
+    /// Dimension exponents of the expression.
+    pub exponents: [i32; N],
+
+    /// Kind of the expression. `None` for an expression of only units of kind [`crate::Kind`].
+    pub kind: Option<TypeId>,
+
+    /// Unit names and powers of the expression with the index of the candidate chosen for each.
+    units: [(&'a str, i32, usize); MAX_UNITS],
+    len: usize,
+}
+
+impl<const N: usize> ResolvedUnitExpression<'_, N> {
+    /// Computes the conversion factor of the expression for the given underlying storage type
+    /// using [`factor_from_expression`]. The exact factor is the product of the conversion
+    /// factors of the chosen units. `factor` is called with each unit name of the expression and
+    /// the index of the chosen candidate, in the order candidates were reported, and returns the
+    /// conversion factor of the candidate.
+    ///
+    /// [`factor_from_expression`]: crate::RuntimeConversion::factor_from_expression
+    ///
+    /// ## Generic Parameters
+    /// * `V`: Underlying storage type.
+    #[must_use]
+    pub fn factor<V>(&self, factor: &mut dyn FnMut(&str, usize) -> Option<V::T>) -> Option<V::T>
+    where
+        V: crate::RuntimeConversion<V>,
+    {
+        use crate::ConversionFactor;
+
+        V::factor_from_expression(self.coefficient, &mut || {
+            let mut result = <V::T as crate::num::One>::one();
+
+            for &(name, power, index) in &self.units[..self.len] {
+                result = result * factor(name, index)?.powi(power);
+            }
+
+            Some(result)
+        })
+    }
+}
+
+impl<'a> UnitExpression<'a> {
+    /// Parses the given unit expression. Returns `None` if the expression is malformed, contains
+    /// too many units, or nests parentheses too deeply.
+    #[must_use]
+    pub fn parse(s: &'a str) -> Option<Self> {
+        let mut parser = Parser {
+            s,
+            position: 0,
+            depth: 0,
+            expression: UnitExpression { units: [("", 0); MAX_UNITS], len: 0 },
+        };
+
+        parser.product()?;
+
+        if parser.position == s.len() {
+            Some(parser.expression)
+        } else {
+            None
+        }
+    }
+
+    /// Resolves the conversion coefficient of the expression to the base units of a system of
+    /// quantities.
+    ///
+    /// * `exponents`: Dimension exponents that the expression must have.
+    /// * `kind`: Kind the expression must have. Units of a kind other than [`crate::Kind`] must
+    ///   all be of the same kind which then becomes the kind of the expression. An expression of
+    ///   only units of kind [`crate::Kind`], e.g. `N·m` for torque, matches any kind.
+    /// * `candidates`: Called with each unit name of the expression and a callback that is
+    ///   invoked for every unit of the system matching the name with the unit's coefficient,
+    ///   dimension exponents, and kind. Ambiguous names are resolved by choosing the first
+    ///   combination of candidates with the expected dimension and kind.
+    #[must_use]
+    pub fn coefficient<const N: usize>(
+        &self,
+        exponents: &[i32; N],
+        kind: TypeId,
+        candidates: &mut dyn FnMut(&str, &mut UnitCandidate<'_, N>),
+    ) -> Option<ResolvedUnitExpression<'a, N>> {
+        let mut found = [[None; MAX_CANDIDATES]; MAX_UNITS];
+
+        for (&(name, _), found) in self.units[..self.len].iter().zip(found.iter_mut()) {
+            let mut count = 0;
+
+            candidates(name, &mut |coefficient, exponents, kind| {
+                if count < MAX_CANDIDATES {
+                    found[count] = Some(Candidate { index: count, coefficient, exponents, kind });
+                    count += 1;
+                }
+            });
+
+            if count == 0 {
+                return None;
+            }
+        }
+
+        let mut chosen = [0; MAX_UNITS];
+        let coefficient = search(
+            &self.units[..self.len],
+            &found[..self.len],
+            &mut chosen[..self.len],
+            [0; N],
+            None,
+            1.0,
+            exponents,
+            kind,
+        )?;
+
+        Some(self.resolved(coefficient, *exponents, Some(kind), chosen))
+    }
+
+    /// Resolves the conversion coefficient, dimension exponents, and kind of the expression when
+    /// the dimension isn't known in advance. Ambiguous unit names resolve to the unit with the
+    /// simplest dimension, e.g. `h` resolves to `hour` rather than `planck_constant`, and then to
+    /// the first such unit. The kind is `None` for an expression of only units of kind
+    /// [`crate::Kind`]. Returns `None` if a unit isn't found, units of different kinds are
+    /// combined, or an exponent overflows. See [`coefficient`](Self::coefficient) for
+    /// `candidates`.
+    #[must_use]
+    pub fn resolve<const N: usize>(
+        &self,
+        candidates: &mut dyn FnMut(&str, &mut UnitCandidate<'_, N>),
+    ) -> Option<ResolvedUnitExpression<'a, N>> {
+        let mut coefficient = 1.0;
+        let mut exponents = [0_i32; N];
+        let mut kind = None;
+        let mut chosen = [0; MAX_UNITS];
+
+        for (&(name, power), chosen) in self.units[..self.len].iter().zip(chosen.iter_mut()) {
+            let mut found: Option<(u32, Candidate<N>)> = None;
+            let mut index = 0;
+
+            candidates(name, &mut |coefficient, exponents, candidate_kind| {
+                let compatible = candidate_kind == TypeId::of::<dyn crate::Kind>()
+                    || kind.map_or(true, |kind| kind == candidate_kind);
+                let complexity = exponents.iter().map(|e| e.unsigned_abs()).sum();
+
+                if compatible && found.map_or(true, |(c, _)| complexity < c) {
+                    found = Some((
+                        complexity,
+                        Candidate { index, coefficient, exponents, kind: candidate_kind },
+                    ));
+                }
+
+                index += 1;
+            });
+
+            let (_, candidate) = found?;
+
+            *chosen = candidate.index;
+
+            if candidate.kind != TypeId::of::<dyn crate::Kind>() {
+                kind = Some(candidate.kind);
+            }
+
+            for (e, c) in exponents.iter_mut().zip(candidate.exponents.iter()) {
+                *e = c.checked_mul(power).and_then(|c| e.checked_add(c))?;
+            }
+
+            coefficient *= crate::num::Float::powi(candidate.coefficient, power);
+        }
+
+        Some(self.resolved(coefficient, exponents, kind, chosen))
+    }
+
+    /// Creates a resolved expression from the expression and the index of the candidate chosen
+    /// for each unit.
+    fn resolved<const N: usize>(
+        &self,
+        coefficient: f64,
+        exponents: [i32; N],
+        kind: Option<TypeId>,
+        chosen: [usize; MAX_UNITS],
+    ) -> ResolvedUnitExpression<'a, N> {
+        let mut units = [("", 0, 0); MAX_UNITS];
+
+        for ((unit, &(name, power)), &index) in
+            units.iter_mut().zip(self.units.iter()).zip(chosen.iter())
+        {
+            *unit = (name, power, index);
+        }
+
+        ResolvedUnitExpression { coefficient, exponents, kind, units, len: self.len }
+    }
+}
+
+/// Depth-first search for the first combination of candidates with the expected dimension and
+/// kind. Returns the conversion coefficient of the combination and stores the index of the chosen
+/// candidate of each unit in `chosen`.
+#[allow(clippy::too_many_arguments)]
+fn search<const N: usize>(
+    units: &[(&str, i32)],
+    found: &[[Option<Candidate<N>>; MAX_CANDIDATES]],
+    chosen: &mut [usize],
+    exponents: [i32; N],
+    kind: Option<TypeId>,
+    coefficient: f64,
+    expected_exponents: &[i32; N],
+    expected_kind: TypeId,
+) -> Option<f64> {
+    let (&(_, power), candidates) = match (units.first(), found.first()) {
+        (Some(unit), Some(candidates)) => (unit, candidates),
+        _ => {
+            return if exponents == *expected_exponents
+                && kind.map_or(true, |kind| kind == expected_kind)
+            {
+                Some(coefficient)
+            } else {
+                None
+            };
+        }
+    };
+
+    for candidate in candidates.iter().flatten() {
+        let kind = if candidate.kind == TypeId::of::<dyn crate::Kind>() {
+            kind
+        } else {
+            match kind {
+                Some(kind) if kind != candidate.kind => continue,
+                _ => Some(candidate.kind),
+            }
+        };
+        let mut next = exponents;
+        let overflow = next.iter_mut().zip(candidate.exponents.iter()).any(|(e, c)| {
+            match c.checked_mul(power).and_then(|c| e.checked_add(c)) {
+                Some(v) => {
+                    *e = v;
+
+                    false
+                }
+                None => true,
+            }
+        });
+
+        if overflow {
+            continue;
+        }
+
+        let coefficient = coefficient * crate::num::Float::powi(candidate.coefficient, power);
+        chosen[0] = candidate.index;
+
+        let result = search(
+            &units[1..],
+            &found[1..],
+            &mut chosen[1..],
+            next,
+            kind,
+            coefficient,
+            expected_exponents,
+            expected_kind,
+        );
+
+        if result.is_some() {
+            return result;
+        }
+    }
+
+    None
+}
+
+/// Recursive descent parser for unit expressions.
+struct Parser<'a> {
+    s: &'a str,
+    position: usize,
+    depth: usize,
+    expression: UnitExpression<'a>,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses `term (operator term)*` where operator is multiplication or division.
+    fn product(&mut self) -> Option<()> {
+        self.term()?;
+
+        loop {
+            let whitespace = self.whitespace();
+
+            match self.peek() {
+                Some('*' | '·' | '⋅') => {
+                    self.bump();
+                    self.term()?;
+                }
+                Some('/') => {
+                    self.bump();
+
+                    let start = self.expression.len;
+
+                    self.term()?;
+                    self.pow(start, -1)?;
+                }
+                None | Some(')') => return Some(()),
+                Some(_) if whitespace => self.term()?,
+                Some(_) => return None,
+            }
+        }
+    }
+
+    /// Parses `(unit | '(' product ')') exponent?`.
+    fn term(&mut self) -> Option<()> {
+        let start = self.expression.len;
+        let _ = self.whitespace();
+
+        if self.peek() == Some('(') {
+            if self.depth == MAX_DEPTH {
+                return None;
+            }
+
+            self.bump();
+            self.depth += 1;
+            self.product()?;
+            self.depth -= 1;
+
+            if self.peek() != Some(')') {
+                return None;
+            }
+
+            self.bump();
+        } else {
+            let begin = self.position;
+
+            while let Some(c) = self.peek() {
+                if is_delimiter(c) {
+                    break;
+                }
+
+                self.bump();
+            }
+
+            match &self.s[begin..self.position] {
+                "" => return None,
+                // Dimensionless placeholder, e.g. `1/s`.
+                "1" => {}
+                unit => {
+                    if self.expression.len == MAX_UNITS {
+                        return None;
+                    }
+
+                    self.expression.units[self.expression.len] = (unit, 1);
+                    self.expression.len += 1;
+                }
+            }
+        }
+
+        let exponent = self.exponent()?;
+
+        self.pow(start, exponent)
+    }
+
+    /// Parses an optional exponent: `^` followed by an optionally parenthesized and signed
+    /// integer, or a superscript integer. Returns `1` when no exponent is given.
+    fn exponent(&mut self) -> Option<i32> {
+        if self.peek() == Some('^') {
+            self.bump();
+
+            let parenthesized = self.peek() == Some('(');
+
+            if parenthesized {
+                self.bump();
+            }
+
+            let sign = match self.peek() {
+                Some('-' | '−') => {
+                    self.bump();
+
+                    -1
+                }
+                Some('+') => {
+                    self.bump();
+
+                    1
+                }
+                _ => 1,
+            };
+            let exponent = self.digits(|c| c.to_digit(10))?;
+
+            if parenthesized {
+                if self.peek() != Some(')') {
+                    return None;
+                }
+
+                self.bump();
+            }
+
+            exponent.checked_mul(sign)
+        } else {
+            let sign = match self.peek() {
+                Some('⁻') => {
+                    self.bump();
+
+                    -1
+                }
+                Some('⁺') => {
+                    self.bump();
+
+                    1
+                }
+                Some(c) if superscript_digit(c).is_some() => 1,
+                _ => return Some(1),
+            };
+
+            self.digits(superscript_digit)?.checked_mul(sign)
+        }
+    }
+
+    /// Parses one or more digits using the given digit conversion function.
+    fn digits(&mut self, digit: fn(char) -> Option<u32>) -> Option<i32> {
+        let mut value: Option<i32> = None;
+
+        while let Some(d) = self.peek().and_then(digit) {
+            self.bump();
+            value = Some(value.unwrap_or(0).checked_mul(10)?.checked_add(d as i32)?);
+        }
+
+        value
+    }
+
+    /// Raises units parsed since `start` to the given power.
+    fn pow(&mut self, start: usize, exponent: i32) -> Option<()> {
+        for (_, e) in self.expression.units[start..self.expression.len].iter_mut() {
+            *e = e.checked_mul(exponent)?;
+        }
+
+        Some(())
+    }
+
+    /// Skips whitespace and returns `true` if any whitespace was skipped.
+    fn whitespace(&mut self) -> bool {
+        let start = self.position;
+
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+
+            self.bump();
+        }
+
+        start != self.position
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+        }
+    }
+}
+
+/// Returns `true` if the character ends a unit name in a unit expression.
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(c, '*' | '·' | '⋅' | '/' | '^' | '(' | ')' | '⁻' | '⁺')
+        || superscript_digit(c).is_some()
+}
+
+/// Converts a superscript digit to its value.
+fn superscript_digit(c: char) -> Option<u32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴' => Some(4),
+        '⁵' => Some(5),
+        '⁶' => Some(6),
+        '⁷' => Some(7),
+        '⁸' => Some(8),
+        '⁹' => Some(9),
+        _ => None,
+    }
+}
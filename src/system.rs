@@ -140,6 +140,56 @@ macro_rules! system {
             type Kind: ?Sized;
         }
 
+        /// Trait providing runtime access to the exponents and kind of a [`Dimension`].
+        /// Implemented for every dimension.
+        ///
+        #[doc = doc_example!()]
+        /// # use uom::si::{energy, RuntimeDimension};
+        /// assert_eq!([2, 1, -2, 0, 0, 0, 0], energy::Dimension::exponents());
+        /// assert_eq!(Some("Energy"), energy::Dimension::quantity().map(|q| q.name()));
+        /// ```
+        pub trait RuntimeDimension: Dimension {
+            /// Exponents of the factors corresponding to the base quantities of the dimension.
+            #[must_use]
+            fn exponents() -> [i32; BASE_QUANTITIES];
+
+            /// [`TypeId`]($crate::lib::any::TypeId) of the dimension's kind or `None` for the
+            /// default kind, [`Kind`]($crate::Kind).
+            #[must_use]
+            fn kind() -> Option<$crate::lib::any::TypeId>;
+
+            /// First quantity in [`QUANTITIES`] with the same dimension and kind.
+            #[must_use]
+            fn quantity() -> Option<&'static QuantityInfo> {
+                let exponents = Self::exponents();
+                let kind = Self::kind();
+
+                QUANTITIES.iter().find(|q| q.exponents == exponents && (q.kind)() == kind)
+            }
+        }
+
+        impl<D> RuntimeDimension for D
+        where
+            D: Dimension + ?Sized,
+            D::Kind: 'static,
+        {
+            #[inline(always)]
+            fn exponents() -> [i32; BASE_QUANTITIES] {
+                exponents::<D>()
+            }
+
+            fn kind() -> Option<$crate::lib::any::TypeId> {
+                let kind = $crate::lib::any::TypeId::of::<D::Kind>();
+
+                if kind == $crate::lib::any::TypeId::of::<dyn $crate::Kind>() {
+                    None
+                }
+                else {
+                    Some(kind)
+                }
+            }
+        }
+
         /// Marker trait to identify a [system of units][units] based on a set of [base units][base]
         /// of a [system of quantities][quantities].
         ///
@@ -182,6 +232,49 @@ macro_rules! system {
             /// Unit plural description.
             #[must_use = "method returns a static value"]
             fn plural() -> &'static str;
+
+            /// Unit [siunitx](https://ctan.org/pkg/siunitx) markup, e.g. `\meter`. `None` when
+            /// the markup is generated from the abbreviation.
+            #[must_use = "method returns a static value"]
+            fn markup() -> Option<&'static str> {
+                None
+            }
+        }
+
+        /// Trait to identify the unit enum of a quantity, e.g. `length::Units`, or a
+        /// [`CustomUnit`]($crate::registry::CustomUnit), allowing a quantity to be created, read,
+        /// and displayed in a unit selected at runtime.
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Underlying storage type trait is implemented for.
+        pub trait RuntimeUnit<V>: Copy
+        where
+            V: $crate::Conversion<V>,
+        {
+            /// Unit abbreviation.
+            #[must_use]
+            fn abbreviation(&self) -> &str;
+
+            /// Unit singular description.
+            #[must_use]
+            fn singular(&self) -> &str;
+
+            /// Unit plural description.
+            #[must_use]
+            fn plural(&self) -> &str;
+
+            /// Unit siunitx markup, if specified.
+            #[must_use]
+            fn markup(&self) -> Option<&str>;
+
+            /// Coefficient to convert the unit to the base unit of the quantity.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            fn coefficient(&self) -> V::T;
+
+            /// Constant to add to a value when converting the unit to the base unit of the
+            /// quantity.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            fn constant(&self, op: $crate::ConstantOp) -> V::T;
         }
 
         /// Property of a phenomenon, body or substance, where the property has a magnitude that
@@ -301,20 +394,55 @@ macro_rules! system {
             U: Units<V> + ?Sized,
             V: $crate::Conversion<V>,
             N: $crate::Conversion<V, T = V::T>,
+        {
+            from_base_factor::<D, U, V>(v, N::coefficient(), N::constant($crate::ConstantOp::Sub))
+        }
+
+        /// Convert a value from base units to a unit with the given conversion coefficient and
+        /// constant.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn from_base_factor<D, U, V>(v: &V, n_coef: V::T, n_cons: V::T) -> V
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+        {
+            use $crate::ConversionFactor;
+
+            from_base_conversion::<D, U, V>(v, n_coef, n_cons).value()
+        }
+
+        /// Convert a value from base units to a unit with the given conversion coefficient and
+        /// constant. Returns the converted value as a conversion factor before it is truncated to
+        /// the underlying storage type.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn from_base_conversion<D, U, V>(v: &V, n_coef: V::T, n_cons: V::T) -> V::T
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
         {
             use $crate::typenum::Integer;
             use $crate::{Conversion, ConversionFactor};
 
             let v = v.conversion();
-            let n_coef = N::coefficient();
             let f = V::coefficient() $(* U::$name::coefficient().powi(D::$symbol::to_i32()))+;
-            let n_cons = N::constant($crate::ConstantOp::Sub);
 
             if n_coef < f {
-                (v * (f / n_coef) - n_cons).value()
+                v * (f / n_coef) - n_cons
             }
             else {
-                (v / (n_coef / f) - n_cons).value()
+                v / (n_coef / f) - n_cons
             }
         }
 
@@ -332,24 +460,436 @@ macro_rules! system {
             U: Units<V> + ?Sized,
             V: $crate::Conversion<V>,
             N: $crate::Conversion<V, T = V::T>,
+        {
+            to_base_factor::<D, U, V>(
+                v.conversion() + N::constant($crate::ConstantOp::Add),
+                N::coefficient())
+        }
+
+        /// Convert a value, including any conversion constant, from a unit with the given
+        /// conversion coefficient to base units.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn to_base_factor<D, U, V>(v: V::T, n_coef: V::T) -> V
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+        {
+            use $crate::ConversionFactor;
+
+            to_base_conversion::<D, U, V>(v, n_coef).value()
+        }
+
+        /// Convert a value, including any conversion constant, from a unit with the given
+        /// conversion coefficient to base units. Returns the converted value as a conversion factor
+        /// before it is truncated to the underlying storage type.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn to_base_conversion<D, U, V>(v: V::T, n_coef: V::T) -> V::T
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
         {
             use $crate::typenum::Integer;
             use $crate::{Conversion, ConversionFactor};
 
-            let v = v.conversion();
-            let n_coef = N::coefficient();
             let f = V::coefficient() $(* U::$name::coefficient().powi(D::$symbol::to_i32()))+;
-            let n_cons = N::constant($crate::ConstantOp::Add);
 
             if n_coef >= f {
-                ((v + n_cons) * (n_coef / f)).value()
+                v * (n_coef / f)
+            }
+            else {
+                (v * n_coef) / f
+            }
+        }
+
+        /// Convert a value from the given unit to base units using checked arithmetic. Returns the
+        /// exact converted value as a conversion factor or `None` if an intermediate result
+        /// overflows.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        /// * `N`: Unit.
+        #[inline(always)]
+        fn to_base_checked<D, U, V, N>(v: &V) -> Option<V::T>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num_traits::CheckedAdd + $crate::num_traits::CheckedMul
+                + $crate::num_traits::CheckedDiv,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::num_traits::{CheckedAdd, CheckedDiv, CheckedMul};
+
+            let f = base_factor_checked::<D, U, V>()?;
+
+            v.conversion()
+                .checked_add(&N::constant($crate::ConstantOp::Add))?
+                .checked_mul(&N::coefficient().checked_div(&f)?)
+        }
+
+        /// Convert a value from base units to the given unit using checked arithmetic. Returns the
+        /// exact converted value as a conversion factor or `None` if an intermediate result
+        /// overflows.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        /// * `N`: Unit.
+        #[inline(always)]
+        fn from_base_checked<D, U, V, N>(v: &V) -> Option<V::T>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num_traits::CheckedSub + $crate::num_traits::CheckedMul
+                + $crate::num_traits::CheckedDiv,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::num_traits::{CheckedDiv, CheckedMul, CheckedSub};
+
+            let f = base_factor_checked::<D, U, V>()?;
+
+            v.conversion()
+                .checked_mul(&f.checked_div(&N::coefficient())?)?
+                .checked_sub(&N::constant($crate::ConstantOp::Sub))
+        }
+
+        /// Conversion factor of the given dimension's base units calculated using checked
+        /// arithmetic. Returns `None` if an intermediate result overflows.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn base_factor_checked<D, U, V>() -> Option<V::T>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num_traits::CheckedMul + $crate::num_traits::CheckedDiv,
+        {
+            use $crate::num::One;
+            use $crate::num_traits::{CheckedDiv, CheckedMul};
+            use $crate::typenum::Integer;
+            use $crate::Conversion;
+
+            let f = V::coefficient();
+            $(let mut p = <V::T as One>::one();
+
+            for _ in 0..D::$symbol::to_i32().unsigned_abs() {
+                p = p.checked_mul(&U::$name::coefficient())?;
+            }
+
+            let f = if D::$symbol::to_i32() < 0 {
+                f.checked_div(&p)?
             }
             else {
-                (((v + n_cons) * n_coef) / f).value()
+                f.checked_mul(&p)?
+            };)+
+
+            Some(f)
+        }
+
+        /// Split a conversion factor into the underlying storage type, truncated towards zero, and
+        /// the remainder lost by truncation.
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn split_value<V>(f: V::T) -> (V, V::T)
+        where
+            V: $crate::Conversion<V>,
+            V::T: $crate::lib::clone::Clone,
+        {
+            use $crate::ConversionFactor;
+
+            let v = f.clone().value();
+            let r = f - v.conversion();
+
+            (v, r)
+        }
+
+        /// Round a conversion factor to the underlying storage type using the given rounding mode.
+        /// Saturates at the bounds of the underlying storage type.
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Value underlying storage type.
+        #[inline(always)]
+        fn round_value<V>(f: V::T, mode: $crate::RoundingMode) -> V
+        where
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone
+                + $crate::num_traits::CheckedAdd + $crate::num_traits::CheckedSub,
+            V::T: $crate::lib::clone::Clone,
+        {
+            use $crate::num::{One, Zero};
+            use $crate::RoundingMode;
+
+            let (v, r) = split_value::<V>(f);
+            let zero = <V::T as Zero>::zero();
+            let up = |v: V| v.checked_add(&V::one()).unwrap_or(v);
+            let down = |v: V| v.checked_sub(&V::one()).unwrap_or(v);
+
+            match mode {
+                RoundingMode::Trunc => v,
+                RoundingMode::Floor if r < zero => down(v),
+                RoundingMode::Ceil if r > zero => up(v),
+                RoundingMode::Floor | RoundingMode::Ceil => v,
+                RoundingMode::HalfEven => {
+                    // Compare twice the remainder to one to avoid representing one half and
+                    // negative values with unsigned underlying storage types.
+                    let one = <V::T as One>::one();
+                    let twice = r.clone() + r;
+                    let odd = !(v.clone() % (V::one() + V::one())).is_zero();
+
+                    if twice > one || (twice == one && odd) {
+                        up(v)
+                    }
+                    else if twice.clone() + one.clone() < zero || ((twice + one).is_zero() && odd) {
+                        down(v)
+                    }
+                    else {
+                        v
+                    }
+                }
+            }
+        }
+
+        /// Number of base quantities in the system of quantities.
+        pub const BASE_QUANTITIES: usize = [$(stringify!($symbol)),+].len();
+
+        /// Exponents of the factors corresponding to the base quantities of the given dimension.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        #[allow(dead_code)]
+        #[inline(always)]
+        const fn exponents<D>() -> [i32; BASE_QUANTITIES]
+        where
+            D: Dimension + ?Sized,
+        {
+            use $crate::typenum::Integer;
+
+            [$(<D::$symbol as Integer>::I32),+]
+        }
+
+        /// Unit of the system matching a unit name in a unit expression.
+        #[derive(Clone, Copy)]
+        struct CandidateUnit {
+            /// Prefix split from the unit name.
+            prefix: Option<&'static $crate::str::Prefix>,
+
+            /// Type of the unit enum of the unit's quantity.
+            units: $crate::lib::any::TypeId,
+
+            /// Position of the unit in the units of its quantity.
+            unit: usize,
+        }
+
+        /// Calls `f` with the coefficient, dimension exponents, and kind of every non-affine unit
+        /// of the system with the given abbreviation, singular, or plural description. When
+        /// `prefixes` is `true` and no unit matches exactly, units matching after splitting a
+        /// leading prefix from the name are used instead.
+        #[allow(dead_code)]
+        fn unit_candidates(
+            name: &str,
+            prefixes: bool,
+            f: &mut $crate::str::UnitCandidate<'_, BASE_QUANTITIES>,
+        ) {
+            visit_unit_candidates(name, prefixes, &mut |_, coefficient, exponents, kind| {
+                f(coefficient, exponents, kind)
+            });
+        }
+
+        /// Conversion factor of the candidate unit with the given index, in the order candidates
+        /// are reported by [`unit_candidates`], of a unit name in a unit expression. The factor
+        /// is exact for storage types with an exact conversion factor.
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Underlying storage type.
+        #[allow(dead_code)]
+        fn unit_candidate_factor<V>(name: &str, prefixes: bool, index: usize) -> Option<V::T>
+        where
+            V: $crate::RuntimeConversion<V>,
+            $($module::Units: RuntimeUnit<V>,)+
+        {
+            let mut candidate = None;
+            let mut count = 0;
+
+            visit_unit_candidates(name, prefixes, &mut |unit, _, _, _| {
+                if count == index {
+                    candidate = Some(unit);
+                }
+
+                count += 1;
+            });
+
+            let CandidateUnit { prefix, units, unit } = candidate?;
+            let mut coefficient = None;
+
+            $(if units == $crate::lib::any::TypeId::of::<$module::Units>() {
+                coefficient = $module::units().nth(unit).map(|unit| unit.coefficient::<V>());
+            })+
+
+            match prefix {
+                Some(prefix) => Some(V::factor_from_exact(prefix.exact)? * coefficient?),
+                None => coefficient,
+            }
+        }
+
+        /// Calls `f` with every unit matched by [`unit_candidates`] along with its coefficient,
+        /// dimension exponents, and kind.
+        #[allow(dead_code)]
+        fn visit_unit_candidates(
+            name: &str,
+            prefixes: bool,
+            f: &mut dyn FnMut(
+                CandidateUnit, f64, [i32; BASE_QUANTITIES], $crate::lib::any::TypeId),
+        ) {
+            use $crate::lib::any::TypeId;
+            use $crate::str::unit_name_eq;
+
+            let mut found = false;
+
+            $(for (index, unit) in $module::units().enumerate() {
+                #[allow(clippy::float_cmp)]
+                let affine = unit.constant_f64() != 0.0;
+
+                if !affine
+                    && (unit_name_eq(name, unit.abbreviation())
+                        || unit_name_eq(name, unit.singular())
+                        || unit_name_eq(name, unit.plural()))
+                {
+                    found = true;
+                    f(
+                        CandidateUnit {
+                            prefix: None,
+                            units: TypeId::of::<$module::Units>(),
+                            unit: index,
+                        },
+                        unit.coefficient_f64(),
+                        exponents::<$module::Dimension>(),
+                        TypeId::of::<<$module::Dimension as Dimension>::Kind>());
+                }
+            })+
+
+            if !prefixes || found {
+                return;
+            }
+
+            let binary_prefixes = [$($module::binary_prefixes()),+];
+
+            for prefix in $crate::str::PREFIXES {
+                let abbreviation = name.strip_prefix(prefix.symbol).filter(|n| !n.is_empty());
+                let description = name.strip_prefix(prefix.name).filter(|n| !n.is_empty());
+
+                if abbreviation.is_none() && description.is_none() {
+                    continue;
+                }
+
+                let mut binary_prefixes = binary_prefixes.iter();
+
+                $(let binary = binary_prefixes.next() == Some(&true);
+
+                if !prefix.binary || binary {
+                    for (index, unit) in $module::units().enumerate() {
+                        #[allow(clippy::float_cmp)]
+                        let affine = unit.constant_f64() != 0.0;
+
+                        // `is_prefixed` scans all prefixes and units so is only checked once a
+                        // name matches.
+                        if !affine
+                            && (abbreviation.map_or(false, |n| unit_name_eq(n, unit.abbreviation()))
+                                || description.map_or(false, |n| {
+                                    unit_name_eq(n, unit.singular())
+                                        || unit_name_eq(n, unit.plural())
+                                }))
+                            && !unit.is_prefixed()
+                        {
+                            f(
+                                CandidateUnit {
+                                    prefix: Some(prefix),
+                                    units: TypeId::of::<$module::Units>(),
+                                    unit: index,
+                                },
+                                prefix.factor * unit.coefficient_f64(),
+                                exponents::<$module::Dimension>(),
+                                TypeId::of::<<$module::Dimension as Dimension>::Kind>());
+                        }
+                    }
+                })+
             }
         }
 
-        autoconvert_test! {
+        /// Calls `f` with the abbreviation, singular, and plural description of every unit of the
+        /// system.
+        #[allow(dead_code)]
+        fn unit_names(f: &mut dyn FnMut(&'static str)) {
+            $(for unit in $module::units() {
+                f(unit.abbreviation());
+                f(unit.singular());
+                f(unit.plural());
+            })+
+        }
+
+        /// Description of the first quantity of the system with a unit matching the given
+        /// abbreviation, singular, or plural description.
+        #[allow(dead_code)]
+        fn unit_quantity(name: &str) -> Option<&'static str> {
+            use $crate::str::unit_name_eq;
+
+            $(if $module::units().any(|unit| {
+                unit_name_eq(name, unit.abbreviation())
+                    || unit_name_eq(name, unit.singular())
+                    || unit_name_eq(name, unit.plural())
+            }) {
+                return Some($module::description());
+            })+
+
+            None
+        }
+
+        /// Resolve the conversion factor of a unit expression to the base units of the system.
+        /// Returns `None` if a unit isn't found or the expression doesn't have the given
+        /// dimension. See [`unit_candidates`] for `prefixes`.
+        ///
+        /// ## Generic Parameters
+        /// * `D`: Dimension.
+        /// * `V`: Underlying storage type.
+        #[allow(dead_code)]
+        fn unit_expression_factor<D, V>(
+            expression: &$crate::str::UnitExpression<'_>,
+            prefixes: bool,
+        ) -> Option<V::T>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: 'static,
+            V: $crate::RuntimeConversion<V>,
+            $($module::Units: RuntimeUnit<V>,)+
+        {
+            expression
+                .coefficient(
+                    &exponents::<D>(),
+                    $crate::lib::any::TypeId::of::<D::Kind>(),
+                    &mut |name, f| unit_candidates(name, prefixes, f))?
+                .factor::<V>(&mut |name, index| unit_candidate_factor::<V>(name, prefixes, index))
+        }
+
         /// Convert a value from one set of base units to a second.
         ///
         /// ## Generic Parameters
@@ -372,7 +912,7 @@ macro_rules! system {
             (v.conversion() $(* Ur::$name::coefficient().powi(D::$symbol::to_i32())
                     / Ul::$name::coefficient().powi(D::$symbol::to_i32()))+)
                 .value()
-        }}
+        }
 
         #[doc(hidden)]
         macro_rules! impl_ops {
@@ -636,6 +1176,159 @@ macro_rules! system {
                 }
             }}}
 
+            std! {
+            /// Takes the `N`th root of a number. Odd roots of negative numbers are negative and
+            /// even roots of negative numbers are `NAN`.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::area::square_meter;
+            /// use uom::typenum::P4;
+            ///
+            /// let a = Area::new::<square_meter>(4.0);
+            /// let l: Length = (a * a).root::<P4>();
+            /// ```
+            ///
+            /// The input type must have dimensions divisible by `N`:
+            ///
+            #[doc = doc_example!("compile_fail")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::area::square_meter;
+            /// use uom::typenum::P4;
+            ///
+            /// // error[E0271]: type mismatch resolving ...
+            /// let r = Area::new::<square_meter>(16.0).root::<P4>();
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `N`: `typenum::Integer` root.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn root<N>(
+                self
+            ) -> Quantity<$quantities<$($crate::typenum::PartialQuot<D::$symbol, N>),+>, U, V>
+            where
+                $(D::$symbol: $crate::typenum::PartialDiv<N>,
+                <D::$symbol as $crate::typenum::PartialDiv<N>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Div,
+                N: $crate::typenum::Integer + $crate::typenum::NonZero,
+                V: $crate::num::Float,
+            {
+                let n = N::to_i32();
+                let e = V::one() / <V as $crate::num_traits::NumCast>::from(n).unwrap();
+                let value = if self.value < V::zero() && n % 2 != 0 {
+                    -(-self.value).powf(e)
+                }
+                else {
+                    self.value.powf(e)
+                };
+
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value,
+                }
+            }
+
+            /// Raises a quantity to a rational power, `Num / Den`. Odd denominator powers of
+            /// negative numbers are real and even denominator powers of negative numbers are `NAN`.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::area::square_meter;
+            /// use uom::typenum::{P2, P3};
+            ///
+            /// let v: Volume = Area::new::<square_meter>(4.0).powr::<P3, P2>();
+            /// ```
+            ///
+            /// The resulting dimensions must be integral:
+            ///
+            #[doc = doc_example!("compile_fail")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::meter;
+            /// use uom::typenum::{P2, P3};
+            ///
+            /// // error[E0271]: type mismatch resolving ...
+            /// let r = Length::new::<meter>(4.0).powr::<P3, P2>();
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `Num`: `typenum::Integer` numerator of the power.
+            /// * `Den`: `typenum::Integer` denominator of the power.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn powr<Num, Den>(
+                self
+            ) -> Quantity<
+                $quantities<$($crate::typenum::PartialQuot<$crate::typenum::Prod<D::$symbol, Num>, Den>),+>,
+                U, V>
+            where
+                $(D::$symbol: $crate::lib::ops::Mul<Num>,
+                $crate::typenum::Prod<D::$symbol, Num>: $crate::typenum::PartialDiv<Den>,
+                <$crate::typenum::Prod<D::$symbol, Num> as $crate::typenum::PartialDiv<Den>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Mul + $crate::marker::Div,
+                Num: $crate::typenum::Integer,
+                Den: $crate::typenum::Integer + $crate::typenum::NonZero,
+                V: $crate::num::Float,
+            {
+                let (num, den) = (Num::to_i32(), Den::to_i32());
+                let (mut gcd, mut r) = (num, den);
+
+                while r != 0 {
+                    (gcd, r) = (r, gcd % r);
+                }
+
+                // Reduce the power so that the sign is handled for e.g. `P2, P6` as for `P1, P3`.
+                let (num, den) = (num / gcd, den / gcd);
+                let e = <V as $crate::num_traits::NumCast>::from(num).unwrap()
+                    / <V as $crate::num_traits::NumCast>::from(den).unwrap();
+                let value = if self.value < V::zero() && den % 2 != 0 {
+                    let v = (-self.value).powf(e);
+
+                    if num % 2 != 0 { -v } else { v }
+                }
+                else {
+                    self.value.powf(e)
+                };
+
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value,
+                }
+            }
+
+            /// Raises a dimensionless quantity to a floating point power.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::ratio::ratio;
+            /// let r: Ratio = Ratio::new::<ratio>(4.0).powf(0.5);
+            /// ```
+            ///
+            /// The input type must be dimensionless:
+            ///
+            #[doc = doc_example!("compile_fail")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::meter;
+            /// // error[E0271]: type mismatch resolving ...
+            /// let r = Length::new::<meter>(4.0).powf(0.5);
+            /// ```
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn powf(self, e: V) -> Self
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0),+>,
+                D::Kind: $crate::marker::Mul,
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.powf(e),
+                }
+            }}
+
             /// Computes the absolute value of `self`. Returns `NAN` if the quantity is
             /// `NAN`.
             #[must_use = "method returns a new number and does not mutate the original value"]
@@ -746,6 +1439,57 @@ macro_rules! system {
                     value: self.value.min(other.value),
                 }
             }
+
+            /// Creates a struct that formats `self` for display in the base units of the quantity,
+            /// e.g. "2 m·kg·s⁻²". Quantities without a named unit, such as the result of a product
+            /// or quotient, can be displayed this way.
+            ///
+            /// # Examples
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::acceleration::meter_per_second_squared;
+            /// # use uom::si::mass::kilogram;
+            /// # use uom::si::length::meter;
+            /// # use uom::si::time::second;
+            /// # use uom::fmt::Notation::*;
+            /// let m = Mass::new::<kilogram>(2.0);
+            /// let f = m * Acceleration::new::<meter_per_second_squared>(1.0);
+            ///
+            /// assert_eq!("2 m·kg·s⁻²", format!("{}", f.into_base_format_args(Superscript)));
+            /// assert_eq!("2 m*kg/s^2", format!("{}", f.into_base_format_args(Ascii)));
+            ///
+            /// let q = Length::new::<meter>(1.0) / (m * Time::new::<second>(4.0));
+            ///
+            /// assert_eq!("0.125 m/(kg·s)", format!("{}", q.into_base_format_args(Fraction)));
+            /// ```
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_base_format_args(
+                self,
+                notation: $crate::fmt::Notation,
+            ) -> fmt::BaseArguments<D, U, V, U> {
+                self.into_base_format_args_in::<U>(notation)
+            }
+
+            /// Creates a struct that formats `self` for display in the given base units, e.g. "2000
+            /// g·cm/s²" for base units of centimeter, gram, and second. See
+            /// [`into_base_format_args`](#method.into_base_format_args).
+            ///
+            /// ## Generic Parameters
+            /// * `N`: Base units to display the quantity in.
+            #[must_use = "method returns a new object and does not mutate the original one"]
+            pub fn into_base_format_args_in<N>(
+                self,
+                notation: $crate::fmt::Notation,
+            ) -> fmt::BaseArguments<D, U, V, N>
+            where
+                N: Units<V> + ?Sized,
+            {
+                fmt::BaseArguments {
+                    quantity: self,
+                    units: $crate::lib::marker::PhantomData,
+                    notation,
+                }
+            }
         }
 
         // Explicitly definte floating point methods for float and complex storage types.
@@ -1255,121 +1999,1522 @@ macro_rules! system {
             }
         }
 
-        impl<D, U, V> $crate::lib::iter::Sum for Quantity<D, U, V>
+        impl<D, U, V> Quantity<D, U, V>
         where
             D: Dimension + ?Sized,
-            D::Kind: $crate::marker::Add,
             U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::iter::Sum,
+            V: $crate::num::Num + $crate::Conversion<V>,
         {
-            fn sum<I>(iter: I) -> Self
+            /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_add(self, rhs: Self) -> Option<Self>
             where
-                I: Iterator<Item = Self>,
+                D::Kind: $crate::marker::Add,
+                V: $crate::num_traits::CheckedAdd,
             {
-                Quantity {
+                Some(Quantity { value: self.value.checked_add(&rhs.value)?, ..self })
+            }
+
+            /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self>
+            where
+                D::Kind: $crate::marker::Sub,
+                V: $crate::num_traits::CheckedSub,
+            {
+                Some(Quantity { value: self.value.checked_sub(&rhs.value)?, ..self })
+            }
+
+            /// Checked multiplication. Computes `self * rhs`, returning `None` if overflow
+            /// occurred.
+            ///
+            /// ## Generic Parameters
+            /// * `Dr`: Dimension for parameter `rhs`.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_mul<Dr>(
+                self,
+                rhs: Quantity<Dr, U, V>,
+            ) -> Option<Quantity<$quantities<$($crate::typenum::Sum<D::$symbol, Dr::$symbol>),+>, U, V>>
+            where
+                $(D::$symbol: $crate::lib::ops::Add<Dr::$symbol>,
+                <D::$symbol as $crate::lib::ops::Add<Dr::$symbol>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Mul,
+                Dr: Dimension + ?Sized,
+                Dr::Kind: $crate::marker::Mul,
+                V: $crate::num_traits::CheckedMul,
+            {
+                Some(Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: iter.map(|v| { v.value }).sum(),
-                }
+                    value: self.value.checked_mul(&rhs.value)?,
+                })
             }
-        }
 
-        test! {
-        impl<D, U, V> $crate::tests::Test for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::tests::Test,
-        {
-            fn assert_eq(lhs: &Self, rhs: &Self) {
-                $crate::tests::Test::assert_eq(&lhs.value, &rhs.value);
+            /// Checked division. Computes `self / rhs`, returning `None` if `rhs` is zero or
+            /// overflow occurred.
+            ///
+            /// ## Generic Parameters
+            /// * `Dr`: Dimension for parameter `rhs`.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_div<Dr>(
+                self,
+                rhs: Quantity<Dr, U, V>,
+            ) -> Option<Quantity<$quantities<$($crate::typenum::Diff<D::$symbol, Dr::$symbol>),+>, U, V>>
+            where
+                $(D::$symbol: $crate::lib::ops::Sub<Dr::$symbol>,
+                <D::$symbol as $crate::lib::ops::Sub<Dr::$symbol>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Div,
+                Dr: Dimension + ?Sized,
+                Dr::Kind: $crate::marker::Div,
+                V: $crate::num_traits::CheckedDiv,
+            {
+                Some(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.checked_div(&rhs.value)?,
+                })
             }
 
-            fn assert_approx_eq(lhs: &Self, rhs: &Self) {
-                $crate::tests::Test::assert_approx_eq(&lhs.value, &rhs.value);
+            /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs` is zero or
+            /// overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_rem(self, rhs: Self) -> Option<Self>
+            where
+                D::Kind: $crate::marker::Rem,
+                V: $crate::num_traits::CheckedRem,
+            {
+                Some(Quantity { value: self.value.checked_rem(&rhs.value)?, ..self })
             }
 
-            fn eq(lhs: &Self, rhs: &Self) -> bool {
-                $crate::tests::Test::eq(&lhs.value, &rhs.value)
+            /// Checked negation. Computes `-self`, returning `None` if overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn checked_neg(self) -> Option<Self>
+            where
+                D::Kind: $crate::marker::Neg,
+                V: $crate::num_traits::CheckedNeg,
+            {
+                Some(Quantity { value: self.value.checked_neg()?, ..self })
             }
 
-            fn approx_eq(lhs: &Self, rhs: &Self) -> bool {
-                $crate::tests::Test::approx_eq(&lhs.value, &rhs.value)
+            /// Wrapping addition. Computes `self + rhs`, wrapping around at the boundary of the
+            /// underlying storage type.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn wrapping_add(self, rhs: Self) -> Self
+            where
+                D::Kind: $crate::marker::Add,
+                V: $crate::num_traits::WrappingAdd,
+            {
+                Quantity { value: self.value.wrapping_add(&rhs.value), ..self }
             }
-        }}
 
-        impl<D, U, V> $crate::num::Zero for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            D::Kind: $crate::marker::Add,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V>,
-        {
-            fn zero() -> Self {
+            /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the boundary of the
+            /// underlying storage type.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn wrapping_sub(self, rhs: Self) -> Self
+            where
+                D::Kind: $crate::marker::Sub,
+                V: $crate::num_traits::WrappingSub,
+            {
+                Quantity { value: self.value.wrapping_sub(&rhs.value), ..self }
+            }
+
+            /// Wrapping multiplication. Computes `self * rhs`, wrapping around at the boundary of
+            /// the underlying storage type.
+            ///
+            /// ## Generic Parameters
+            /// * `Dr`: Dimension for parameter `rhs`.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn wrapping_mul<Dr>(
+                self,
+                rhs: Quantity<Dr, U, V>,
+            ) -> Quantity<$quantities<$($crate::typenum::Sum<D::$symbol, Dr::$symbol>),+>, U, V>
+            where
+                $(D::$symbol: $crate::lib::ops::Add<Dr::$symbol>,
+                <D::$symbol as $crate::lib::ops::Add<Dr::$symbol>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Mul,
+                Dr: Dimension + ?Sized,
+                Dr::Kind: $crate::marker::Mul,
+                V: $crate::num_traits::WrappingMul,
+            {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: V::zero(),
+                    value: self.value.wrapping_mul(&rhs.value),
                 }
             }
 
-            fn is_zero(&self) -> bool {
-                self.value.is_zero()
+            /// Wrapping negation. Computes `-self`, wrapping around at the boundary of the
+            /// underlying storage type.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn wrapping_neg(self) -> Self
+            where
+                D::Kind: $crate::marker::Neg,
+                V: $crate::num_traits::WrappingNeg,
+            {
+                Quantity { value: self.value.wrapping_neg(), ..self }
             }
-        }
 
-        impl<D, U, V> $crate::ConstZero for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::ConstZero,
-        {
-            const ZERO: Self = Self {
-                dimension: $crate::lib::marker::PhantomData,
-                units: $crate::lib::marker::PhantomData,
-                value: V::ZERO,
-            };
-        }
+            /// Overflowing addition. Computes `self + rhs`, returning the wrapped result along
+            /// with a boolean indicating whether overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool)
+            where
+                D::Kind: $crate::marker::Add,
+                V: $crate::num_traits::ops::overflowing::OverflowingAdd,
+            {
+                let (value, overflow) = self.value.overflowing_add(&rhs.value);
 
-        serde! {
-        impl<D, U, V> $crate::serde::Serialize for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Serialize,
-        {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                (Quantity { value, ..self }, overflow)
+            }
+
+            /// Overflowing subtraction. Computes `self - rhs`, returning the wrapped result along
+            /// with a boolean indicating whether overflow occurred.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool)
             where
-                S: $crate::serde::Serializer
+                D::Kind: $crate::marker::Sub,
+                V: $crate::num_traits::ops::overflowing::OverflowingSub,
             {
-                self.value.serialize(serializer)
+                let (value, overflow) = self.value.overflowing_sub(&rhs.value);
+
+                (Quantity { value, ..self }, overflow)
+            }
+
+            /// Overflowing multiplication. Computes `self * rhs`, returning the wrapped result
+            /// along with a boolean indicating whether overflow occurred.
+            ///
+            /// ## Generic Parameters
+            /// * `Dr`: Dimension for parameter `rhs`.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn overflowing_mul<Dr>(
+                self,
+                rhs: Quantity<Dr, U, V>,
+            ) -> (Quantity<$quantities<$($crate::typenum::Sum<D::$symbol, Dr::$symbol>),+>, U, V>, bool)
+            where
+                $(D::$symbol: $crate::lib::ops::Add<Dr::$symbol>,
+                <D::$symbol as $crate::lib::ops::Add<Dr::$symbol>>::Output: $crate::typenum::Integer,)+
+                D::Kind: $crate::marker::Mul,
+                Dr: Dimension + ?Sized,
+                Dr::Kind: $crate::marker::Mul,
+                V: $crate::num_traits::ops::overflowing::OverflowingMul,
+            {
+                let (value, overflow) = self.value.overflowing_mul(&rhs.value);
+
+                (
+                    Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value,
+                    },
+                    overflow,
+                )
             }
         }
 
-        impl<'de, D, U, V> $crate::serde::Deserialize<'de> for Quantity<D, U, V>
+        impl<D, U, V> Quantity<D, U, V>
         where
             D: Dimension + ?Sized,
             U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Deserialize<'de>,
+            V: $crate::num::Num + $crate::Conversion<V>,
         {
-            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+            /// Cast the quantity to a different underlying storage type, e.g. `f32` to `f64`. The
+            /// dimension, kind, and base units of the quantity are preserved.
+            ///
+            /// ## Generic Parameters
+            /// * `V2`: Underlying storage type to cast to.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn cast<V2>(self) -> Quantity<D, dyn Units<V2, $($name = U::$name),+>, V2>
             where
-                De: $crate::serde::Deserializer<'de>,
+                V2: $crate::num::Num + $crate::Conversion<V2> + $crate::lib::convert::From<V>,
+                $(U::$name: $crate::Conversion<V2, T = V2::T>,)+
             {
-                let value: V = $crate::serde::Deserialize::deserialize(deserializer)?;
-
-                Ok(Quantity {
+                Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value,
-                })
+                    value: V2::from(self.value),
+                }
             }
-        }}
 
-        /// Utilities for formatting and printing quantities.
-        pub mod fmt {
-            use $crate::lib::fmt;
-            use super::{Dimension, Quantity, Unit, Units, from_base};
+            /// Cast the quantity to a different underlying storage type, e.g. `i64` to `f64` or
+            /// `f64` to `i32`. Returns `None` if the value can't be represented by the new
+            /// underlying storage type. The dimension, kind, and base units of the quantity are
+            /// preserved.
+            ///
+            /// ## Generic Parameters
+            /// * `V2`: Underlying storage type to cast to.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn try_cast<V2>(self) -> Option<Quantity<D, dyn Units<V2, $($name = U::$name),+>, V2>>
+            where
+                V: $crate::num::ToPrimitive,
+                V2: $crate::num::Num + $crate::Conversion<V2> + $crate::num_traits::NumCast,
+                $(U::$name: $crate::Conversion<V2, T = V2::T>,)+
+            {
+                Some(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: <V2 as $crate::num_traits::NumCast>::from(self.value)?,
+                })
+            }
+
+            /// Convert the quantity to a different set of base units. The dimension, kind, and
+            /// underlying storage type of the quantity are preserved.
+            ///
+            /// ## Generic Parameters
+            /// * `U2`: Base units to convert to.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            #[inline(always)]
+            pub fn into_units<U2>(self) -> Quantity<D, U2, V>
+            where
+                U2: Units<V> + ?Sized,
+                V: $crate::lib::ops::Mul<V, Output = V>,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: change_base::<D, U2, U, V>(&self.value),
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        macro_rules! impl_checked_ops {
+            ($Trait:path, $fun:ident, $Kind:ident) => {
+                impl<D, U, V> $Trait for Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$Kind,
+                    U: Units<V> + ?Sized,
+                    V: $crate::num::Num + $crate::Conversion<V> + $Trait,
+                {
+                    #[inline(always)]
+                    fn $fun(&self, v: &Self) -> Option<Self> {
+                        Some(Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: self.value.$fun(&v.value)?,
+                        })
+                    }
+                }
+            };
+        }
+
+        impl_checked_ops!($crate::num_traits::CheckedAdd, checked_add, Add);
+        impl_checked_ops!($crate::num_traits::CheckedSub, checked_sub, Sub);
+        impl_checked_ops!($crate::num_traits::CheckedRem, checked_rem, Rem);
+
+        #[doc(hidden)]
+        macro_rules! impl_wrapping_ops {
+            ($Trait:path, $fun:ident, $Kind:ident) => {
+                impl<D, U, V> $Trait for Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$Kind,
+                    U: Units<V> + ?Sized,
+                    V: $crate::num::Num + $crate::Conversion<V> + $Trait,
+                {
+                    #[inline(always)]
+                    fn $fun(&self, v: &Self) -> Self {
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: self.value.$fun(&v.value),
+                        }
+                    }
+                }
+            };
+        }
+
+        impl_wrapping_ops!($crate::num_traits::WrappingAdd, wrapping_add, Add);
+        impl_wrapping_ops!($crate::num_traits::WrappingSub, wrapping_sub, Sub);
+
+        #[doc(hidden)]
+        macro_rules! impl_overflowing_ops {
+            ($Trait:path, $fun:ident, $Kind:ident) => {
+                impl<D, U, V> $Trait for Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$Kind,
+                    U: Units<V> + ?Sized,
+                    V: $crate::num::Num + $crate::Conversion<V> + $Trait,
+                {
+                    #[inline(always)]
+                    fn $fun(&self, v: &Self) -> (Self, bool) {
+                        let (value, overflow) = self.value.$fun(&v.value);
+
+                        (
+                            Quantity {
+                                dimension: $crate::lib::marker::PhantomData,
+                                units: $crate::lib::marker::PhantomData,
+                                value,
+                            },
+                            overflow,
+                        )
+                    }
+                }
+            };
+        }
+
+        impl_overflowing_ops!($crate::num_traits::ops::overflowing::OverflowingAdd,
+            overflowing_add, Add);
+        impl_overflowing_ops!($crate::num_traits::ops::overflowing::OverflowingSub,
+            overflowing_sub, Sub);
+
+        impl<D, U, V> $crate::num_traits::CheckedNeg for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Neg,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::num_traits::CheckedNeg,
+        {
+            #[inline(always)]
+            fn checked_neg(&self) -> Option<Self> {
+                Some(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.checked_neg()?,
+                })
+            }
+        }
+
+        impl<D, U, V> $crate::num_traits::WrappingNeg for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Neg,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::num_traits::WrappingNeg,
+        {
+            #[inline(always)]
+            fn wrapping_neg(&self) -> Self {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.wrapping_neg(),
+                }
+            }
+        }
+
+        impl<D, U, V> $crate::lib::iter::Sum for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Add,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::iter::Sum,
+        {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: iter.map(|v| { v.value }).sum(),
+                }
+            }
+        }
+
+        test! {
+        impl<D, U, V> $crate::tests::Test for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::tests::Test,
+        {
+            fn assert_eq(lhs: &Self, rhs: &Self) {
+                $crate::tests::Test::assert_eq(&lhs.value, &rhs.value);
+            }
+
+            fn assert_approx_eq(lhs: &Self, rhs: &Self) {
+                $crate::tests::Test::assert_approx_eq(&lhs.value, &rhs.value);
+            }
+
+            fn eq(lhs: &Self, rhs: &Self) -> bool {
+                $crate::tests::Test::eq(&lhs.value, &rhs.value)
+            }
+
+            fn approx_eq(lhs: &Self, rhs: &Self) -> bool {
+                $crate::tests::Test::approx_eq(&lhs.value, &rhs.value)
+            }
+        }}
+
+        impl<D, U, V> $crate::num::Zero for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Add,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            fn zero() -> Self {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: V::zero(),
+                }
+            }
+
+            fn is_zero(&self) -> bool {
+                self.value.is_zero()
+            }
+        }
+
+        impl<D, U, V> $crate::ConstZero for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::ConstZero,
+        {
+            const ZERO: Self = Self {
+                dimension: $crate::lib::marker::PhantomData,
+                units: $crate::lib::marker::PhantomData,
+                value: V::ZERO,
+            };
+        }
+
+        serde! {
+        impl<D, U, V> $crate::serde::Serialize for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer
+            {
+                self.value.serialize(serializer)
+            }
+        }
+
+        impl<'de, D, U, V> $crate::serde::Deserialize<'de> for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde::Deserialize<'de>,
+        {
+            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+            where
+                De: $crate::serde::Deserializer<'de>,
+            {
+                let value: V = $crate::serde::Deserialize::deserialize(deserializer)?;
+
+                Ok(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value,
+                })
+            }
+        }}
+
+        schemars! {
+        impl<D, U, V> $crate::schemars::JsonSchema for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: 'static,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::schemars::JsonSchema,
+        {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> $crate::lib::borrow::Cow<'static, str> {
+                $crate::lib::borrow::Cow::Borrowed(
+                    <D as RuntimeDimension>::quantity().map_or("Quantity", QuantityInfo::name))
+            }
+
+            /// Describes the quantity as a bare value in base units annotated with the quantity
+            /// description and the base unit abbreviations, e.g. `"x-unit": "m/s"`.
+            fn json_schema(
+                generator: &mut $crate::schemars::SchemaGenerator,
+            ) -> $crate::schemars::Schema {
+                let units = [$((U::$name::abbreviation(),
+                    <D::$symbol as $crate::typenum::Integer>::to_i32())),+];
+
+                $crate::str::serde_quantity::value_schema(generator.subschema_for::<V>(),
+                    <D as RuntimeDimension>::quantity().map(QuantityInfo::description), &units)
+            }
+        }}
+
+        /// Description of a quantity of the system. See [`QUANTITIES`].
+        #[derive(Clone, Copy)]
+        pub struct QuantityInfo {
+            name: &'static str,
+            description: &'static str,
+            exponents: [i32; BASE_QUANTITIES],
+            kind: fn() -> Option<$crate::lib::any::TypeId>,
+            unit: fn(usize) -> Option<DynUnit>,
+        }
+
+        impl QuantityInfo {
+            /// Quantity name, e.g. `"Length"`.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                self.name
+            }
+
+            /// Quantity description, e.g. `"length"`.
+            #[must_use]
+            pub fn description(&self) -> &'static str {
+                self.description
+            }
+
+            /// Exponents of the factors corresponding to the base quantities of the quantity's
+            /// dimension.
+            #[must_use]
+            pub fn exponents(&self) -> [i32; BASE_QUANTITIES] {
+                self.exponents
+            }
+
+            /// [`TypeId`]($crate::lib::any::TypeId) of the quantity's kind or `None` for the
+            /// default kind, [`Kind`]($crate::Kind).
+            #[must_use]
+            pub fn kind(&self) -> Option<$crate::lib::any::TypeId> {
+                (self.kind)()
+            }
+
+            /// Units of the quantity in the order they are defined.
+            pub fn units(&self) -> impl Iterator<Item = DynUnit> {
+                (0..).map_while(self.unit)
+            }
+        }
+
+        impl $crate::lib::fmt::Debug for QuantityInfo {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                f.debug_struct("QuantityInfo")
+                    .field("name", &self.name)
+                    .field("description", &self.description)
+                    .field("exponents", &self.exponents)
+                    .finish()
+            }
+        }
+
+        /// Quantities of the system in the order they are defined.
+        pub static QUANTITIES: &[QuantityInfo] = &[
+            $(QuantityInfo {
+                name: stringify!($quantity),
+                description: $module::description(),
+                exponents: exponents::<$module::Dimension>(),
+                kind: <$module::Dimension as RuntimeDimension>::kind,
+                unit: |i| $module::Units::ALL.get(i).copied().map(DynUnit::$quantity),
+            },)+
+        ];
+
+        /// Quantities of the system with the given dimension exponents, e.g. `Energy` and
+        /// `Torque` for L²·M·T⁻² in the SI.
+        ///
+        #[doc = doc_example!()]
+        /// # use uom::si::{energy, quantities_with_exponents, RuntimeDimension};
+        /// let mut names = quantities_with_exponents(energy::Dimension::exponents())
+        ///     .map(|q| q.name());
+        ///
+        /// assert_eq!(Some("Energy"), names.next());
+        /// assert_eq!(Some("Torque"), names.next());
+        /// ```
+        pub fn quantities_with_exponents(
+            exponents: [i32; BASE_QUANTITIES],
+        ) -> impl Iterator<Item = &'static QuantityInfo> {
+            QUANTITIES.iter().filter(move |q| q.exponents == exponents)
+        }
+
+        /// Unit of any quantity of the system, e.g. `DynUnit::Length(length::Units::meter)`.
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum DynUnit {
+            $(#[doc = concat!("Unit of [`", stringify!($quantity), "`](", stringify!($module),
+                "::", stringify!($quantity), ").")]
+            $quantity($module::Units),)+
+        }
+
+        impl DynUnit {
+            /// Unit identifier, e.g. `"kilometer"`.
+            #[must_use]
+            pub fn identifier(&self) -> &'static str {
+                match self {
+                    $(DynUnit::$quantity(unit) => unit.identifier(),)+
+                }
+            }
+
+            /// Unit abbreviation.
+            #[must_use]
+            pub fn abbreviation(&self) -> &'static str {
+                match self {
+                    $(DynUnit::$quantity(unit) => unit.abbreviation(),)+
+                }
+            }
+
+            /// Unit singular description.
+            #[must_use]
+            pub fn singular(&self) -> &'static str {
+                match self {
+                    $(DynUnit::$quantity(unit) => unit.singular(),)+
+                }
+            }
+
+            /// Unit plural description.
+            #[must_use]
+            pub fn plural(&self) -> &'static str {
+                match self {
+                    $(DynUnit::$quantity(unit) => unit.plural(),)+
+                }
+            }
+
+            /// Unit siunitx markup, if specified.
+            #[must_use]
+            pub fn markup(&self) -> Option<&'static str> {
+                match self {
+                    $(DynUnit::$quantity(unit) => unit.markup(),)+
+                }
+            }
+        }
+
+        impl $crate::lib::fmt::Display for DynUnit {
+            /// Formats the unit abbreviation.
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter<'_>) -> $crate::lib::fmt::Result {
+                f.pad(self.abbreviation())
+            }
+        }
+
+        $(impl $crate::lib::convert::From<$module::Units> for DynUnit {
+            fn from(unit: $module::Units) -> Self {
+                DynUnit::$quantity(unit)
+            }
+        })+
+
+        /// Quantity with a dimension known only at runtime, e.g. a channel of a data file. The
+        /// value is stored in the base units of the system, [`$units`], along with the exponents
+        /// of the base quantities and an optional [kind](https://jcgm.bipm.org/vim/en/1.2.html).
+        ///
+        /// Addition and subtraction return a [`DimensionError`] when the dimensions or kinds of
+        /// the operands don't match. As with statically dimensioned quantities, a quantity of a
+        /// kind can't be added to a quantity without a kind, e.g. the product of two quantities.
+        /// Multiplication, division, and `powi` combine exponents and
+        /// drop the kind. Multiplication and division panic when an exponent overflows while
+        /// `checked_mul`, `checked_div`, and `powi` return `None`. Statically dimensioned
+        /// quantities convert into a `DynQuantity` with `From` and back with `TryFrom`.
+        ///
+        #[doc = doc_example!()]
+        /// # use uom::si::f32::*;
+        /// # use uom::si::velocity::kilometer_per_hour;
+        /// # use uom::si::DynQuantity;
+        /// # use std::convert::TryFrom;
+        /// let d: DynQuantity<f32> = "36 km/h".parse().unwrap();
+        /// let v = Velocity::try_from(d).unwrap();
+        ///
+        /// assert_eq!(36.0, v.get::<kilometer_per_hour>());
+        /// assert!(Length::try_from(d).is_err());
+        /// assert!((d + DynQuantity::from(Length::default())).is_err());
+        /// ```
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Underlying storage type.
+        #[derive(Clone, Copy, PartialEq)]
+        pub struct DynQuantity<V> {
+            /// Quantity value stored in the base units of the system.
+            pub value: V,
+
+            exponents: [i32; BASE_QUANTITIES],
+            kind: Option<$crate::lib::any::TypeId>,
+        }
+
+        /// Base units of a [`DynQuantity`]. Aliased to avoid conflicts between the name of the
+        /// system of units and generic parameters.
+        type DynQuantityUnits<V> = $units<V>;
+
+        /// Error returned when the dimension or kind of a [`DynQuantity`] doesn't match the
+        /// dimension or kind expected by an operation or conversion.
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum DimensionError {
+            /// The dimensions don't match.
+            Dimension {
+                /// Exponents of the base quantities of the expected dimension.
+                expected: [i32; BASE_QUANTITIES],
+
+                /// Exponents of the base quantities of the dimension found.
+                found: [i32; BASE_QUANTITIES],
+            },
+
+            /// The dimensions match but the kinds don't, e.g. an angle and a ratio.
+            Kind {
+                /// Exponents of the base quantities of the dimension.
+                exponents: [i32; BASE_QUANTITIES],
+            },
+        }
+
+        impl DimensionError {
+            /// Creates a dimension mismatch error, or a kind mismatch error when the exponents are
+            /// equal.
+            fn new(expected: [i32; BASE_QUANTITIES], found: [i32; BASE_QUANTITIES]) -> Self {
+                if expected == found {
+                    DimensionError::Kind { exponents: expected }
+                }
+                else {
+                    DimensionError::Dimension { expected, found }
+                }
+            }
+
+            /// Exponents of the base quantities of the expected dimension.
+            #[must_use]
+            pub fn expected(&self) -> [i32; BASE_QUANTITIES] {
+                match *self {
+                    DimensionError::Dimension { expected, .. } => expected,
+                    DimensionError::Kind { exponents } => exponents,
+                }
+            }
+
+            /// Exponents of the base quantities of the dimension found. Equal to
+            /// [`expected`](Self::expected) when only the kinds don't match.
+            #[must_use]
+            pub fn found(&self) -> [i32; BASE_QUANTITIES] {
+                match *self {
+                    DimensionError::Dimension { found, .. } => found,
+                    DimensionError::Kind { exponents } => exponents,
+                }
+            }
+        }
+
+        impl $crate::lib::fmt::Display for DimensionError {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                match self {
+                    DimensionError::Dimension { expected, found } => {
+                        f.write_str("dimension mismatch: expected ")?;
+                        write_exponents(f, expected)?;
+                        f.write_str(", found ")?;
+                        write_exponents(f, found)
+                    }
+                    DimensionError::Kind { exponents } => {
+                        f.write_str("kind mismatch for dimension ")?;
+                        write_exponents(f, exponents)
+                    }
+                }
+            }
+        }
+
+        std! {
+        impl $crate::lib::error::Error for DimensionError {}
+        }
+
+        /// Writes the given dimension exponents using the abbreviations of the base units of the
+        /// system, e.g. `m^1 s^-1`, or `1` for a dimensionless quantity.
+        fn write_exponents(
+            f: &mut $crate::lib::fmt::Formatter,
+            exponents: &[i32; BASE_QUANTITIES],
+        ) -> $crate::lib::fmt::Result {
+            let abbreviations = [$(<$name::$unit as Unit>::abbreviation()),+];
+            let mut separator = "";
+
+            for (abbreviation, &e) in abbreviations.iter().zip(exponents.iter()) {
+                if 0 != e {
+                    write!(f, "{}{}^{}", separator, abbreviation, e)?;
+                    separator = " ";
+                }
+            }
+
+            if separator.is_empty() {
+                f.write_str("1")?;
+            }
+
+            Ok(())
+        }
+
+        /// Conversion coefficient of the given base units raised to the given dimension
+        /// exponents.
+        ///
+        /// ## Generic Parameters
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        fn base_coefficient<U, V>(exponents: &[i32; BASE_QUANTITIES]) -> V::T
+        where
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+        {
+            use $crate::{Conversion, ConversionFactor};
+
+            IntoIterator::into_iter([$(U::$name::coefficient()),+])
+                .zip(exponents.iter())
+                .fold(V::coefficient(), |f, (c, &e)| f * c.powi(e))
+        }
+
+        /// Convert a value from a unit with the coefficient `from` to a unit with the coefficient
+        /// `to`.
+        ///
+        /// ## Generic Parameters
+        /// * `V`: Value underlying storage type.
+        fn rescale<V>(v: V::T, from: V::T, to: V::T) -> V
+        where
+            V: $crate::Conversion<V>,
+        {
+            use $crate::ConversionFactor;
+
+            if from >= to {
+                (v * (from / to)).value()
+            }
+            else {
+                (v / (to / from)).value()
+            }
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Creates a new quantity from a value in the base units of the system and the
+            /// exponents of the base quantities.
+            #[must_use]
+            pub fn new(value: V, exponents: [i32; BASE_QUANTITIES]) -> Self {
+                DynQuantity {
+                    value,
+                    exponents,
+                    kind: None,
+                }
+            }
+
+            /// Exponents of the base quantities of the quantity's dimension.
+            #[must_use]
+            pub fn exponents(&self) -> [i32; BASE_QUANTITIES] {
+                self.exponents
+            }
+
+            /// [`TypeId`]($crate::lib::any::TypeId) of the quantity's kind or `None` for the
+            /// default kind, [`Kind`]($crate::Kind).
+            #[must_use]
+            pub fn kind(&self) -> Option<$crate::lib::any::TypeId> {
+                self.kind
+            }
+
+            /// Returns `true` if the quantity is dimensionless.
+            #[must_use]
+            pub fn is_dimensionless(&self) -> bool {
+                self.exponents.iter().all(|&e| 0 == e)
+            }
+
+            /// Named quantities of the system the quantity can be converted into.
+            pub fn quantities(&self) -> impl Iterator<Item = &'static QuantityInfo> {
+                let kind = self.kind;
+
+                quantities_with_exponents(self.exponents)
+                    .filter(move |q| kind.map_or(true, |kind| q.kind() == Some(kind)))
+            }
+
+            /// Returns `true` if the quantity can be converted into a quantity with the given
+            /// dimension.
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            #[must_use]
+            pub fn is<D>(&self) -> bool
+            where
+                D: Dimension + ?Sized,
+                D::Kind: 'static,
+            {
+                self.check::<D>().is_ok()
+            }
+
+            /// Verifies that the quantity has the given dimension and a compatible kind.
+            fn check<D>(&self) -> Result<(), DimensionError>
+            where
+                D: Dimension + ?Sized,
+                D::Kind: 'static,
+            {
+                let expected = exponents::<D>();
+
+                if expected == self.exponents
+                    && self.kind.map_or(true, |kind| Some(kind) == D::kind())
+                {
+                    Ok(())
+                }
+                else {
+                    Err(DimensionError::new(expected, self.exponents))
+                }
+            }
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::RuntimeConversion<V>,
+            DynQuantityUnits<V>: Units<V>,
+            $($module::Units: RuntimeUnit<V>,)+
+        {
+            /// Creates a new quantity from a value in the given unit or unit expression, e.g.
+            /// `km`, `kilometers`, or `kg·m/s²`. Units with a non-zero constant factor, e.g.
+            /// `°C`, aren't supported. Returns `None` if the unit isn't recognized.
+            #[must_use]
+            pub fn with_unit(value: V, unit: &str) -> Option<Self> {
+                Self::with_unit_options(value, unit, $crate::str::ParseOptions::new())
+            }
+
+            fn with_unit_options(
+                value: V,
+                unit: &str,
+                options: $crate::str::ParseOptions,
+            ) -> Option<Self> {
+                let prefixes = options.prefixes;
+                let expression = $crate::str::UnitExpression::parse(unit)?
+                    .resolve(&mut |name, f| unit_candidates(name, prefixes, f))?;
+                let coefficient = expression.factor::<V>(&mut |name, index| {
+                    unit_candidate_factor::<V>(name, prefixes, index)
+                })?;
+
+                Some(DynQuantity {
+                    value: rescale::<V>(
+                        value.conversion(),
+                        coefficient,
+                        base_coefficient::<DynQuantityUnits<V>, V>(&expression.exponents)),
+                    exponents: expression.exponents,
+                    kind: expression.kind,
+                })
+            }
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::RuntimeConversion<V>
+                + $crate::lib::clone::Clone + $crate::lib::str::FromStr,
+            V::Err: $crate::lib::fmt::Display,
+            DynQuantityUnits<V>: Units<V>,
+            $($module::Units: RuntimeUnit<V>,)+
+        {
+            /// Parses a string into a quantity using the given options. See
+            /// [`ParseOptions`]($crate::str::ParseOptions).
+            ///
+            /// # Errors
+            /// Returns a [`ParseQuantityError`]($crate::str::ParseQuantityError) when the string
+            /// can't be parsed.
+            pub fn from_str_with(
+                s: &str,
+                options: $crate::str::ParseOptions,
+            ) -> Result<Self, $crate::str::ParseQuantityError> {
+                const DESCRIPTION: &str = "quantity";
+
+                $crate::str::parse_layouts(
+                    s,
+                    options,
+                    DESCRIPTION,
+                    &mut |value, unit| Ok(Self::with_unit_options(value, unit, options)),
+                    &|unit| $crate::str::ParseQuantityError::unknown_unit(
+                        DESCRIPTION, $crate::str::span(s, unit), unit, unit_names, None))
+            }
+        }
+
+        impl<V> $crate::lib::str::FromStr for DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::RuntimeConversion<V>
+                + $crate::lib::clone::Clone + $crate::lib::str::FromStr,
+            V::Err: $crate::lib::fmt::Display,
+            DynQuantityUnits<V>: Units<V>,
+            $($module::Units: RuntimeUnit<V>,)+
+        {
+            type Err = $crate::str::ParseQuantityError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_str_with(s, $crate::str::ParseOptions::new())
+            }
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Multiplies two quantities. Returns `None` when an exponent of the resulting
+            /// dimension overflows.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(DynQuantity {
+                    exponents: combine_exponents(&self.exponents, &rhs.exponents,
+                        i32::checked_add)?,
+                    value: self.value * rhs.value,
+                    kind: None,
+                })
+            }
+
+            /// Divides two quantities. Returns `None` when an exponent of the resulting dimension
+            /// overflows.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                Some(DynQuantity {
+                    exponents: combine_exponents(&self.exponents, &rhs.exponents,
+                        i32::checked_sub)?,
+                    value: self.value / rhs.value,
+                    kind: None,
+                })
+            }
+        }
+
+        /// Combines the exponents of two dimensions element-wise. Returns `None` when `f` does.
+        fn combine_exponents(
+            l: &[i32; BASE_QUANTITIES],
+            r: &[i32; BASE_QUANTITIES],
+            f: fn(i32, i32) -> Option<i32>,
+        ) -> Option<[i32; BASE_QUANTITIES]> {
+            let mut exponents = *l;
+
+            for (l, r) in exponents.iter_mut().zip(r.iter()) {
+                *l = f(*l, *r)?;
+            }
+
+            Some(exponents)
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Float + $crate::Conversion<V>,
+        {
+            /// Raises a quantity to an integer power. Returns `None` when an exponent of the
+            /// resulting dimension overflows.
+            #[must_use = "method returns a new number and does not mutate the original value"]
+            pub fn powi(self, e: i32) -> Option<Self> {
+                let mut exponents = self.exponents;
+
+                for exponent in exponents.iter_mut() {
+                    *exponent = exponent.checked_mul(e)?;
+                }
+
+                Some(DynQuantity {
+                    value: self.value.powi(e),
+                    exponents,
+                    kind: None,
+                })
+            }
+        }
+
+        impl<V> $crate::lib::fmt::Debug for DynQuantity<V>
+        where
+            V: $crate::lib::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                self.value.fmt(f)?;
+
+                if self.exponents.iter().any(|&e| 0 != e) {
+                    f.write_str(" ")?;
+                    write_exponents(f, &self.exponents)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        macro_rules! impl_dyn_ops {
+            (
+                $AddSubTrait:ident, $addsub_fun:ident, $addsub_op:tt,
+                $MulDivTrait:ident, $muldiv_fun:ident, $muldiv_op:tt, $checked_fun:ident
+            ) => {
+                impl<V> $crate::lib::ops::$AddSubTrait for DynQuantity<V>
+                where
+                    V: $crate::num::Num + $crate::Conversion<V>,
+                {
+                    type Output = Result<Self, DimensionError>;
+
+                    fn $addsub_fun(self, rhs: Self) -> Self::Output {
+                        // Kinds must be equal, matching statically dimensioned quantities where
+                        // e.g. an angle can't be added to a ratio.
+                        if self.exponents == rhs.exponents && self.kind == rhs.kind {
+                            Ok(DynQuantity {
+                                value: self.value $addsub_op rhs.value,
+                                exponents: self.exponents,
+                                kind: self.kind,
+                            })
+                        }
+                        else {
+                            Err(DimensionError::new(self.exponents, rhs.exponents))
+                        }
+                    }
+                }
+
+                impl<V> $crate::lib::ops::$MulDivTrait for DynQuantity<V>
+                where
+                    V: $crate::num::Num + $crate::Conversion<V>,
+                {
+                    type Output = Self;
+
+                    fn $muldiv_fun(self, rhs: Self) -> Self::Output {
+                        self.$checked_fun(rhs).expect("dimension exponent overflow")
+                    }
+                }
+
+                impl<V> $crate::lib::ops::$MulDivTrait<V> for DynQuantity<V>
+                where
+                    V: $crate::num::Num + $crate::Conversion<V>,
+                {
+                    type Output = Self;
+
+                    fn $muldiv_fun(self, rhs: V) -> Self::Output {
+                        DynQuantity {
+                            value: self.value $muldiv_op rhs,
+                            ..self
+                        }
+                    }
+                }
+            };
+        }
+
+        impl_dyn_ops!(Add, add, +, Mul, mul, *, checked_mul);
+        impl_dyn_ops!(Sub, sub, -, Div, div, /, checked_div);
+
+        impl<D, U, V> $crate::lib::convert::From<Quantity<D, U, V>> for DynQuantity<V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: 'static,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+            DynQuantityUnits<V>: Units<V>,
+        {
+            fn from(quantity: Quantity<D, U, V>) -> Self {
+                let exponents = exponents::<D>();
+
+                DynQuantity {
+                    value: rescale::<V>(
+                        quantity.value.conversion(),
+                        base_coefficient::<U, V>(&exponents),
+                        base_coefficient::<DynQuantityUnits<V>, V>(&exponents)),
+                    exponents,
+                    kind: D::kind(),
+                }
+            }
+        }
+
+        impl<D, U, V> $crate::lib::convert::TryFrom<DynQuantity<V>> for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: 'static,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+            DynQuantityUnits<V>: Units<V>,
+        {
+            type Error = DimensionError;
+
+            fn try_from(quantity: DynQuantity<V>) -> Result<Self, Self::Error> {
+                quantity.check::<D>()?;
+
+                Ok(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: rescale::<V>(
+                        quantity.value.conversion(),
+                        base_coefficient::<DynQuantityUnits<V>, V>(&quantity.exponents),
+                        base_coefficient::<U, V>(&quantity.exponents)),
+                })
+            }
+        }
+
+        /// Error returned by [`convert`] and [`convert_value`].
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum ConvertError {
+            /// The value or a unit couldn't be parsed.
+            Parse($crate::str::ParseQuantityError),
+
+            /// The units don't have the same dimension and kind.
+            Dimension(DimensionError),
+        }
+
+        impl $crate::lib::convert::From<$crate::str::ParseQuantityError> for ConvertError {
+            fn from(e: $crate::str::ParseQuantityError) -> Self {
+                ConvertError::Parse(e)
+            }
+        }
+
+        impl $crate::lib::fmt::Display for ConvertError {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                match self {
+                    ConvertError::Parse(e) => $crate::lib::fmt::Display::fmt(e, f),
+                    ConvertError::Dimension(e) => $crate::lib::fmt::Display::fmt(e, f),
+                }
+            }
+        }
+
+        std! {
+        impl $crate::lib::error::Error for ConvertError {
+            fn source(&self) -> Option<&(dyn $crate::lib::error::Error + 'static)> {
+                match self {
+                    ConvertError::Parse(e) => Some(e),
+                    ConvertError::Dimension(e) => Some(e),
+                }
+            }
+        }}
+
+        /// Conversion of a unit to base units.
+        #[derive(Clone, Copy)]
+        struct UnitConversion {
+            coefficient: f64,
+            constant: f64,
+            exponents: [i32; BASE_QUANTITIES],
+            kind: Option<$crate::lib::any::TypeId>,
+        }
+
+        /// Maximum number of candidate conversions considered for an ambiguous unit, e.g. `h` is
+        /// both the abbreviation of `hour` and `planck_constant`.
+        const MAX_CONVERSIONS: usize = 4;
+
+        /// Candidate conversions of every unit of the system, including units with a non-zero
+        /// constant factor, with the given abbreviation, singular, or plural description. Units
+        /// with a non-zero constant factor, e.g. `degree_celsius` of thermodynamic temperature,
+        /// come before units without, e.g. `degree_celsius` of temperature interval. When no unit
+        /// matches, the unit is resolved as a unit expression instead.
+        fn unit_conversions(unit: &str) -> [Option<UnitConversion>; MAX_CONVERSIONS] {
+            use $crate::str::unit_name_eq;
+
+            let mut conversions = [None; MAX_CONVERSIONS];
+            let mut count = 0;
+
+            for affine in [true, false] {
+                $(for u in $module::units() {
+                    #[allow(clippy::float_cmp)]
+                    let is_affine = u.constant_f64() != 0.0;
+
+                    if count < MAX_CONVERSIONS
+                        && is_affine == affine
+                        && (unit_name_eq(unit, u.abbreviation())
+                            || unit_name_eq(unit, u.singular())
+                            || unit_name_eq(unit, u.plural()))
+                    {
+                        conversions[count] = Some(UnitConversion {
+                            coefficient: u.coefficient_f64(),
+                            constant: u.constant_f64(),
+                            exponents: exponents::<$module::Dimension>(),
+                            kind: <$module::Dimension as RuntimeDimension>::kind(),
+                        });
+                        count += 1;
+                    }
+                })+
+            }
+
+            if 0 == count {
+                conversions[0] = $crate::str::UnitExpression::parse(unit)
+                    .and_then(|expression| {
+                        expression.resolve(&mut |name, f| unit_candidates(name, false, f))
+                    })
+                    .map(|expression| UnitConversion {
+                        coefficient: expression.coefficient,
+                        constant: 0.0,
+                        exponents: expression.exponents,
+                        kind: expression.kind,
+                    });
+            }
+
+            conversions
+        }
+
+        /// Converts a value between two units, or unit expressions, of any quantity of the
+        /// system, e.g. `convert_value(72.0, "°F", "°C")`. Units are matched by abbreviation,
+        /// singular, or plural description and must have the same dimension and kind. Units with
+        /// a non-zero constant factor, e.g. `°C`, are converted correctly but can't be part of a
+        /// unit expression.
+        ///
+        #[doc = doc_example!()]
+        /// # use uom::si::convert_value;
+        /// assert_eq!(8.04672, convert_value(5.0, "mi", "km").unwrap());
+        /// assert_eq!(100.0, convert_value(212.0, "°F", "degrees Celsius").unwrap().round());
+        /// assert_eq!(1.0, convert_value(1.0, "J", "N·m").unwrap());
+        /// assert!(convert_value(1.0, "m", "s").is_err());
+        /// ```
+        ///
+        /// # Errors
+        /// Returns a [`ConvertError`] when a unit isn't recognized or the units have a different
+        /// dimension or kind.
+        pub fn convert_value(value: f64, from: &str, to: &str) -> Result<f64, ConvertError> {
+            let unknown_unit = |unit: &str| {
+                ConvertError::Parse($crate::str::ParseQuantityError::unknown_unit(
+                    "quantity", 0..unit.len(), unit, unit_names, None))
+            };
+            let from_conversions = unit_conversions(from);
+            let to_conversions = unit_conversions(to);
+            let (first_from, first_to) = match (from_conversions[0], to_conversions[0]) {
+                (Some(f), Some(t)) => (f, t),
+                (None, _) => return Err(unknown_unit(from)),
+                (_, None) => return Err(unknown_unit(to)),
+            };
+
+            for f in from_conversions.iter().flatten() {
+                for t in to_conversions.iter().flatten() {
+                    if f.exponents == t.exponents && f.kind == t.kind {
+                        return Ok((value + f.constant) * f.coefficient / t.coefficient
+                            - t.constant);
+                    }
+                }
+            }
+
+            Err(ConvertError::Dimension(DimensionError::new(first_to.exponents,
+                first_from.exponents)))
+        }
+
+        /// Converts a quantity string, e.g. `"72 °F"`, to the given unit. See
+        /// [`convert_value`].
+        ///
+        #[doc = doc_example!()]
+        /// # use uom::si::convert;
+        /// assert_eq!(8.04672, convert("5 mi", "km").unwrap());
+        /// assert_eq!(22.0, convert("72 °F", "°C").unwrap().round());
+        /// ```
+        ///
+        /// # Errors
+        /// Returns a [`ConvertError`] when the string can't be parsed, a unit isn't recognized,
+        /// or the units have a different dimension or kind.
+        pub fn convert(s: &str, to: &str) -> Result<f64, ConvertError> {
+            $crate::str::parse_layouts(
+                s,
+                $crate::str::ParseOptions::new(),
+                "quantity",
+                &mut |value, unit| match convert_value(value, unit, to) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(ConvertError::Parse(e)) if e.unit() == Some(unit) => Ok(None),
+                    Err(e) => Err(e),
+                },
+                &|unit| $crate::str::ParseQuantityError::unknown_unit(
+                    "quantity", $crate::str::span(s, unit), unit, unit_names, None))
+        }
+
+        wire! {
+        /// Error returned by [`Quantity::decode_wire`].
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum DecodeError {
+            /// The encoded quantity is malformed or its value can't be represented.
+            Wire($crate::wire::WireError),
+
+            /// The encoded dimension doesn't match the dimension of the quantity.
+            Dimension(DimensionError),
+        }
+
+        impl $crate::lib::fmt::Display for DecodeError {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                match self {
+                    DecodeError::Wire(e) => $crate::lib::fmt::Display::fmt(e, f),
+                    DecodeError::Dimension(e) => $crate::lib::fmt::Display::fmt(e, f),
+                }
+            }
+        }
+
+        std! {
+        impl $crate::lib::error::Error for DecodeError {
+            fn source(&self) -> Option<&(dyn $crate::lib::error::Error + 'static)> {
+                match self {
+                    DecodeError::Wire(e) => Some(e),
+                    DecodeError::Dimension(e) => Some(e),
+                }
+            }
+        }}
+
+        /// Decimal scale of the given base units raised to the given dimension exponents, e.g. 3
+        /// for a length in base units of kilometers. Returns an error when a base unit used by
+        /// the dimension isn't a decimal multiple of the reference base unit.
+        ///
+        /// ## Generic Parameters
+        /// * `U`: Base units.
+        /// * `V`: Value underlying storage type.
+        fn wire_scale<U, V>(
+            exponents: &[i32; BASE_QUANTITIES],
+        ) -> Result<i32, $crate::wire::WireError>
+        where
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num::ToPrimitive,
+        {
+            use $crate::num::ToPrimitive;
+            use $crate::Conversion;
+
+            IntoIterator::into_iter([$(U::$name::coefficient().to_f64()),+])
+                .zip(exponents.iter())
+                .filter(|&(_, &e)| 0 != e)
+                .try_fold(0, |scale, (c, &e)| {
+                    c.and_then($crate::wire::decimal_exponent)
+                        .map(|k| scale + k * e)
+                        .ok_or($crate::wire::WireError::Scale)
+                })
+        }
+
+        impl<D, U, V> Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::RuntimeConversion<V> + $crate::wire::WireValue,
+            V::T: $crate::num::ToPrimitive,
+        {
+            /// Length in bytes of the quantity when encoded with
+            /// [`encode_wire`](Self::encode_wire).
+            pub const WIRE_LEN: usize =
+                $crate::wire::header_len(BASE_QUANTITIES) + <V as $crate::wire::WireValue>::SIZE;
+
+            /// Encodes the quantity, its dimension, and the decimal scale of its base units at
+            /// the beginning of `buffer`. Returns the number of bytes written,
+            /// [`WIRE_LEN`](Self::WIRE_LEN). See [`wire`]($crate::wire) for the format.
+            ///
+            /// # Errors
+            /// Returns a [`WireError`]($crate::wire::WireError) when the buffer is too small, a
+            /// dimension exponent is outside of the range -8 through 7, or a base unit used by the
+            /// dimension isn't a decimal multiple of the reference base unit, e.g. foot.
+            pub fn encode_wire(&self, buffer: &mut [u8]) -> Result<usize, $crate::wire::WireError> {
+                use $crate::wire::WireError;
+
+                let exponents = exponents::<D>();
+
+                if buffer.len() < Self::WIRE_LEN {
+                    return Err(WireError::BufferTooSmall(Self::WIRE_LEN));
+                }
+
+                let len = $crate::wire::write_header(
+                    buffer, V::TAG, &exponents, wire_scale::<U, V>(&exponents)?)?;
+
+                self.value.write(&mut buffer[len..]);
+
+                Ok(Self::WIRE_LEN)
+            }
+
+            /// Decodes a quantity encoded with [`encode_wire`](Self::encode_wire) from the
+            /// beginning of `buffer`. The value is converted to the storage type of the quantity
+            /// and rescaled from the sender's base units. Returns the quantity and the number of
+            /// bytes read. The kind of the quantity isn't verified.
+            ///
+            /// # Errors
+            /// Returns a [`DecodeError`] when the buffer is malformed or too small, the dimension
+            /// doesn't match, or the rescaled value can't be represented by the storage type,
+            /// e.g. it overflows or is fractional for an integer storage type.
+            pub fn decode_wire(buffer: &[u8]) -> Result<(Self, usize), DecodeError> {
+                use $crate::wire::WireError;
+
+                let mut found = [0; BASE_QUANTITIES];
+                let (raw, scale, len) = $crate::wire::read_quantity(buffer, &mut found)
+                    .map_err(DecodeError::Wire)?;
+                let expected = exponents::<D>();
+
+                if expected != found {
+                    return Err(DecodeError::Dimension(DimensionError::Dimension {
+                        expected,
+                        found,
+                    }));
+                }
+
+                let shift = scale - wire_scale::<U, V>(&expected).map_err(DecodeError::Wire)?;
+                let value = V::from_raw(raw)
+                    .and_then(|value| value.rescale(shift))
+                    .ok_or(DecodeError::Wire(WireError::Value))?;
+
+                Ok((
+                    Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value,
+                    },
+                    len,
+                ))
+            }
+        }}
+
+        /// Utilities for formatting and printing quantities.
+        pub mod fmt {
+            use $crate::lib::fmt;
+            use super::{Dimension, Quantity, RuntimeUnit, Unit, Units, from_base, from_base_factor};
             use $crate::num::Num;
             use $crate::Conversion;
             use $crate::fmt::DisplayStyle;
@@ -1444,6 +3589,168 @@ macro_rules! system {
                 pub(super) quantity: Quantity<D, U, V>,
             }
 
+            /// A struct to specify a display style and a unit selected at runtime for a given
+            /// quantity.
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::{meter, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// let l = Length::new::<meter>(1.0);
+            /// let a = l.into_format_args_in(Units::centimeter, Description);
+            ///
+            /// assert_eq!("100 centimeters", format!("{}", a));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            /// * `U`: Base units.
+            /// * `V`: Value underlying storage type.
+            /// * `N`: Unit enum of the quantity.
+            pub struct UnitsArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V>,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) unit: N,
+                pub(super) style: DisplayStyle,
+            }
+
+            /// A struct to display a quantity as a sum of units, e.g. "5 ft 3 in". Every unit but
+            /// the last is displayed with an integer value. The last unit is displayed with the
+            /// remainder rounded as specified by [`RoundingMode`]($crate::RoundingMode).
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::{foot, inch, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// # use uom::RoundingMode;
+            /// let l = Length::new::<inch>(75.2);
+            /// let a = l.into_sum_format_args(&[Units::foot, Units::inch], Abbreviation,
+            ///     RoundingMode::HalfEven);
+            ///
+            /// assert_eq!("6 ft 3 in", format!("{}", a));
+            /// assert_eq!("6 ft 3.2 in", format!("{:.1}", a));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            /// * `U`: Base units.
+            /// * `V`: Value underlying storage type.
+            /// * `N`: Unit enum of the quantity.
+            #[allow(missing_debug_implementations)] // Only `Display` is implemented.
+            pub struct SumArguments<'a, D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V>,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) units: &'a [N],
+                pub(super) style: DisplayStyle,
+                pub(super) rounding: $crate::RoundingMode,
+            }
+
+            /// A struct to display a quantity in the unit with an automatically selected SI
+            /// prefix, e.g. "3 mW" or "3 GW". See [`Prefixes`]($crate::fmt::Prefixes).
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::power::{watt, Units};
+            /// # use uom::fmt::DisplayStyle::*;
+            /// # use uom::fmt::Prefixes;
+            /// let p = Power::new::<watt>(3.0_E9);
+            /// let a = p.into_prefixed_format_args(Units::watt, Prefixes::Engineering,
+            ///     Abbreviation);
+            ///
+            /// assert_eq!("3 GW", format!("{}", a));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            /// * `U`: Base units.
+            /// * `V`: Value underlying storage type.
+            /// * `N`: Unit enum of the quantity.
+            #[allow(missing_debug_implementations)] // Only `Display` is implemented.
+            pub struct PrefixedArguments<'a, D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V>,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) unit: N,
+                pub(super) prefixes: $crate::fmt::Prefixes<'a, N>,
+                pub(super) style: DisplayStyle,
+            }
+
+            /// A struct to display a quantity with a localized number format and unit name, e.g.
+            /// "1.500,5 Meter". See [`Locale`]($crate::fmt::locale::Locale).
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::{meter, Units};
+            /// # use uom::fmt::locale::{FRENCH, GERMAN};
+            /// let l = Length::new::<meter>(1_500.5);
+            ///
+            /// assert_eq!("1.500,5 Meter",
+            ///     format!("{}", l.into_localized_format_args(Units::meter, &GERMAN)));
+            /// assert_eq!("1\u{202f}500,5\u{a0}mètres",
+            ///     format!("{}", l.into_localized_format_args(Units::meter, &FRENCH)));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            /// * `U`: Base units.
+            /// * `V`: Value underlying storage type.
+            /// * `N`: Unit enum of the quantity.
+            /// * `L`: Locale.
+            #[allow(missing_debug_implementations)] // Only `Display` is implemented.
+            pub struct LocalizedArguments<'a, D, U, V, N, L>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V>,
+                L: ?Sized,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) unit: N,
+                pub(super) locale: &'a L,
+            }
+
+            /// A struct to display a quantity in base units, e.g. "2 m·kg·s⁻²". See
+            /// [`Notation`]($crate::fmt::Notation).
+            ///
+            #[doc = doc_example!()]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::velocity::meter_per_second;
+            /// # use uom::fmt::Notation::*;
+            /// let v = Velocity::new::<meter_per_second>(3.0);
+            ///
+            /// assert_eq!("3 m·s⁻¹", format!("{}", v.into_base_format_args(Superscript)));
+            /// assert_eq!("3 m/s", format!("{}", v.into_base_format_args(Fraction)));
+            /// ```
+            ///
+            /// ## Generic Parameters
+            /// * `D`: Dimension.
+            /// * `U`: Base units.
+            /// * `V`: Value underlying storage type.
+            /// * `N`: Base units to display the quantity in.
+            #[allow(missing_debug_implementations)] // Only `Display` is implemented.
+            pub struct BaseArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V>,
+                N: Units<V> + ?Sized,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) units: $crate::lib::marker::PhantomData<N>,
+                pub(super) notation: $crate::fmt::Notation,
+            }
+
             impl<D, N> $crate::lib::clone::Clone for Arguments<D, N>
             where
                 D: Dimension + ?Sized,
@@ -1485,6 +3792,142 @@ macro_rules! system {
             {
             }
 
+            impl<D, U, V, N> $crate::lib::clone::Clone for UnitsArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+                N: $crate::lib::marker::Copy,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        quantity: self.quantity.clone(),
+                        unit: self.unit,
+                        style: self.style,
+                    }
+                }
+            }
+
+            impl<D, U, V, N> $crate::lib::marker::Copy for UnitsArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::marker::Copy,
+                N: $crate::lib::marker::Copy,
+            {
+            }
+
+            impl<'a, D, U, V, N> $crate::lib::clone::Clone for SumArguments<'a, D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        quantity: self.quantity.clone(),
+                        units: self.units,
+                        style: self.style,
+                        rounding: self.rounding,
+                    }
+                }
+            }
+
+            impl<'a, D, U, V, N> $crate::lib::clone::Clone for PrefixedArguments<'a, D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+                N: $crate::lib::marker::Copy,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        quantity: self.quantity.clone(),
+                        unit: self.unit,
+                        prefixes: self.prefixes,
+                        style: self.style,
+                    }
+                }
+            }
+
+            impl<'a, D, U, V, N, L> $crate::lib::clone::Clone
+                for LocalizedArguments<'a, D, U, V, N, L>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+                N: $crate::lib::marker::Copy,
+                L: ?Sized,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        quantity: self.quantity.clone(),
+                        unit: self.unit,
+                        locale: self.locale,
+                    }
+                }
+            }
+
+            impl<D, U, V, N> $crate::lib::clone::Clone for BaseArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+                N: Units<V> + ?Sized,
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        quantity: self.quantity.clone(),
+                        units: $crate::lib::marker::PhantomData,
+                        notation: self.notation,
+                    }
+                }
+            }
+
+            impl<D, U, V, N> $crate::lib::marker::Copy for BaseArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::marker::Copy,
+                N: Units<V> + ?Sized,
+            {
+            }
+
+            impl<D, U, V, N> fmt::Display for BaseArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: Num + Conversion<V> + fmt::Display,
+                N: Units<V> + ?Sized,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    use $crate::typenum::Integer;
+                    use $crate::ConversionFactor;
+
+                    let from = V::coefficient()
+                        $(* U::$name::coefficient().powi(D::$symbol::to_i32()))+;
+                    let to = V::coefficient()
+                        $(* N::$name::coefficient().powi(D::$symbol::to_i32()))+;
+                    let v = self.quantity.value.conversion();
+                    let value: V = if from >= to {
+                        (v * (from / to)).value()
+                    }
+                    else {
+                        ((v * from) / to).value()
+                    };
+                    let units = [$((N::$name::abbreviation(), D::$symbol::to_i32())),+];
+
+                    value.fmt(f)?;
+
+                    if units.iter().any(|&(_, exponent)| exponent != 0) {
+                        f.write_str(" ")?;
+                        $crate::fmt::write_units(f, &units, self.notation)?;
+                    }
+
+                    Ok(())
+                }
+            }
+
             macro_rules! format_arguments {
                 ($style:ident) => {
                     impl<D, U, V, N> fmt::$style for QuantityArguments<D, U, V, N>
@@ -1497,14 +3940,34 @@ macro_rules! system {
                         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                             let value = from_base::<D, U, V, N>(&self.quantity.value);
 
+                            let style = self.arguments.style;
+
+                            $crate::fmt::write_value_start(f, style)?;
+                            value.fmt(f)?;
+                            $crate::fmt::write_unit(f, style, N::abbreviation(),
+                                if value.is_one() { N::singular() } else { N::plural() },
+                                N::markup())
+                        }
+                    }
+
+                    impl<D, U, V, N> fmt::$style for UnitsArguments<D, U, V, N>
+                    where
+                        D: Dimension + ?Sized,
+                        U: Units<V> + ?Sized,
+                        V: Num + Conversion<V> + fmt::$style,
+                        N: RuntimeUnit<V>,
+                    {
+                        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            let value = from_base_factor::<D, U, V>(&self.quantity.value,
+                                self.unit.coefficient(),
+                                self.unit.constant($crate::ConstantOp::Sub));
+
+                            $crate::fmt::write_value_start(f, self.style)?;
                             value.fmt(f)?;
-                            write!(f, " {}",
-                                match self.arguments.style {
-                                    DisplayStyle::Abbreviation => N::abbreviation(),
-                                    DisplayStyle::Description => {
-                                        if value.is_one() { N::singular() } else { N::plural() }
-                                    },
-                                })
+                            $crate::fmt::write_unit(f, self.style, self.unit.abbreviation(),
+                                if value.is_one() { self.unit.singular() }
+                                else { self.unit.plural() },
+                                self.unit.markup())
                         }
                     }
                 };
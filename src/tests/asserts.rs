@@ -27,6 +27,59 @@ assert_not_impl_any!(DisplayStyle:
     Binary, Display, Eq, Hash, LowerExp, LowerHex, Ord, Octal, PartialEq, PartialOrd, UpperExp,
     UpperHex);
 #[rustfmt::skip]
+assert_impl_all!(crate::fmt::Prefixes<'static, length::Units>:
+    Clone, Copy, Debug, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::Prefixes<'static, length::Units>:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::fmt::Prefixes<'static, length::Units>:
+    Binary, Display, Eq, Hash, LowerExp, LowerHex, Ord, Octal, PartialEq, PartialOrd, UpperExp,
+    UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::Notation:
+    Clone, Copy, Debug, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::Notation:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::fmt::Notation:
+    Binary, Display, Eq, Hash, LowerExp, LowerHex, Ord, Octal, PartialEq, PartialOrd, UpperExp,
+    UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::PluralCategory:
+    Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::PluralCategory:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::fmt::locale::PluralCategory:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::Operands:
+    Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::Operands:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::fmt::locale::Operands:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::Catalog:
+    Clone, Copy, Debug, crate::fmt::locale::Locale, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::fmt::locale::Catalog:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::fmt::locale::Catalog:
+    Binary, Display, Eq, Hash, LowerExp, LowerHex, Ord, Octal, PartialEq, PartialOrd, UpperExp,
+    UpperHex);
+#[rustfmt::skip]
 assert_impl_all!(ParseQuantityError:
     Clone, Debug, Display, Eq, PartialEq, Send, Sync, Unpin);
 #[cfg(feature = "std")]
@@ -36,6 +89,70 @@ assert_impl_all!(ParseQuantityError:
 #[rustfmt::skip]
 assert_not_impl_any!(ParseQuantityError:
     Binary, Copy, Hash, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(ParseQuantityErrorKind:
+    Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(ParseQuantityErrorKind:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(ParseQuantityErrorKind:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(Separator:
+    Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(Separator:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(Separator:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(Trailing:
+    Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(Trailing:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(Trailing:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(ParseOptions:
+    Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(ParseOptions:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(ParseOptions:
+    Binary, Display, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::registry::CustomUnit<'static, length::Dimension, f32>:
+    Clone, Copy, Debug, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::registry::CustomUnit<'static, length::Dimension, f32>:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::registry::CustomUnit<'static, length::Dimension, f32>:
+    Binary, Display, Eq, Hash, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::registry::RegistryError:
+    Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Send, Sync, Unpin);
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+assert_impl_all!(crate::registry::RegistryError:
+    RefUnwindSafe, UnwindSafe);
+#[rustfmt::skip]
+assert_not_impl_any!(crate::registry::RegistryError:
+    Binary, LowerExp, LowerHex, Ord, Octal, PartialOrd, UpperExp, UpperHex);
+#[rustfmt::skip]
+assert_impl_all!(crate::registry::UnitRegistry<'static, length::Dimension, f32,
+        [Option<crate::registry::CustomUnit<'static, length::Dimension, f32>>; 1]>:
+    Debug, Send, Sync, Unpin);
 
 storage_types! {
     types: Float;
@@ -56,6 +173,13 @@ storage_types! {
         RefUnwindSafe, UnwindSafe);
     assert_not_impl_any!(QuantityArguments<Q<Z0, Z0, Z0>, U<V>, V, meter>:
         Binary, Eq, Hash, LowerHex, Octal, Ord, PartialEq, PartialOrd, UpperHex);
+    assert_impl_all!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        Clone, Copy, Debug, Display, LowerExp, Send, Sync, Unpin, UpperExp);
+    #[cfg(feature = "std")]
+    assert_impl_all!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        RefUnwindSafe, UnwindSafe);
+    assert_not_impl_any!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        Binary, Eq, Hash, LowerHex, Octal, Ord, PartialEq, PartialOrd, UpperHex);
 }
 
 storage_types! {
@@ -78,6 +202,14 @@ storage_types! {
         RefUnwindSafe, UnwindSafe);
     assert_not_impl_any!(QuantityArguments<Q<Z0, Z0, Z0>, U<V>, V, meter>:
         Eq, Hash, Ord, PartialEq, PartialOrd);
+    assert_impl_all!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        Binary, Clone, Copy, Debug, Display, LowerExp, LowerHex, Octal, Send, Sync, Unpin, UpperExp,
+        UpperHex);
+    #[cfg(feature = "std")]
+    assert_impl_all!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        RefUnwindSafe, UnwindSafe);
+    assert_not_impl_any!(UnitsArguments<Q<P1, Z0, Z0>, U<V>, V, length::Units>:
+        Eq, Hash, Ord, PartialEq, PartialOrd);
 }
 
 storage_types! {
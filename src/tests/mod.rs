@@ -1,7 +1,7 @@
 //! Tests for `uom` macros.
 
 #[allow(unused_imports)]
-use self::fmt::{Arguments, QuantityArguments};
+use self::fmt::{Arguments, QuantityArguments, UnitsArguments};
 use self::length::{kilometer, meter};
 use self::mass::kilogram;
 use self::thermodynamic_temperature::{degree_fahrenheit, kelvin};
@@ -10,7 +10,7 @@ use crate::lib::fmt::Debug;
 use crate::lib::marker::PhantomData;
 #[allow(unused_imports)]
 use crate::num::{Float, FromPrimitive, One, Saturating, Signed, Zero};
-use crate::str::ParseQuantityError;
+use crate::str::{ParseOptions, ParseQuantityError, ParseQuantityErrorKind, Separator, Trailing};
 use crate::{ConstantOp, Conversion, ConversionFactor};
 use quickcheck::TestResult;
 #[allow(unused_imports)]
@@ -23,7 +23,7 @@ mod length {
         dimension: Q<P1, Z0, Z0>;
         units {
             @kilometer: 1.0_E3; "km", "kilometer", "kilometers";
-            @meter: 1.0_E0; "m", "meter", "meters";
+            @meter: 1.0_E0; "m", "meter", "meters", r"\meter";
         }
     }
 }
@@ -15,6 +15,19 @@ fn description() {
     assert_eq!("mass", mass::description());
 }
 
+#[test]
+fn localized_description() {
+    use crate::fmt::locale::{ENGLISH, FRENCH, GERMAN};
+
+    assert_eq!("length", length::localized_description(&ENGLISH));
+    assert_eq!("Länge", length::localized_description(&GERMAN));
+    assert_eq!("longueur", length::localized_description(&FRENCH));
+    assert_eq!(
+        "thermodynamic temperature",
+        thermodynamic_temperature::localized_description(&ENGLISH)
+    );
+}
+
 #[test]
 fn abbreviation() {
     assert_eq!("km", kilometer::abbreviation());
@@ -65,6 +78,87 @@ fn units() {
     assert_eq!(1, units_iter.count());
 }
 
+#[test]
+fn units_all() {
+    assert_eq!(&[length::Units::kilometer, length::Units::meter], length::Units::ALL);
+    assert!(length::units().eq(length::Units::ALL.iter().copied()));
+    assert_eq!(&[mass::Units::kilogram], mass::Units::ALL);
+}
+
+#[test]
+fn units_identifier() {
+    assert_eq!("kilometer", length::Units::kilometer.identifier());
+    assert_eq!(
+        "degree_fahrenheit",
+        thermodynamic_temperature::Units::degree_fahrenheit.identifier()
+    );
+}
+
+#[test]
+fn units_from_str() {
+    assert_eq!(Ok(length::Units::kilometer), "km".parse());
+    assert_eq!(Ok(length::Units::kilometer), "kilometer".parse());
+    assert_eq!(Ok(length::Units::meter), "meters".parse());
+    assert_eq!(Ok(thermodynamic_temperature::Units::degree_fahrenheit), "°F".parse());
+
+    let e = "kms".parse::<length::Units>().unwrap_err();
+
+    assert_eq!(ParseQuantityErrorKind::UnknownUnit, e.kind());
+    assert_eq!(0..3, e.span());
+    assert_eq!(Some("km"), e.suggestions().next());
+    assert_eq!(Some("mass"), "kg".parse::<length::Units>().unwrap_err().other_quantity());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn units_display() {
+    assert_eq!("km", format!("{}", length::Units::kilometer));
+    assert_eq!("°F", format!("{}", thermodynamic_temperature::Units::degree_fahrenheit));
+    assert_eq!("m   ", format!("{:4}", length::Units::meter));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn units_eq_hash_ord() {
+    use std::collections::HashSet;
+
+    let units: HashSet<_> = length::units().chain(length::units()).collect();
+
+    assert_eq!(2, units.len());
+    assert!(units.contains(&length::Units::meter));
+    assert_ne!(length::Units::kilometer, length::Units::meter);
+    assert!(length::Units::kilometer < length::Units::meter);
+}
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[test]
+fn units_serde() {
+    use crate::str::serde_abbreviation;
+
+    assert_eq!("\"kilometer\"", serde_json::to_string(&length::Units::kilometer).unwrap());
+    assert_eq!(length::Units::meter, serde_json::from_str::<length::Units>("\"meter\"").unwrap());
+    assert!(serde_json::from_str::<length::Units>("\"km\"").is_err());
+
+    let mut json = Vec::new();
+
+    serde_abbreviation::serialize(
+        &length::Units::kilometer,
+        &mut serde_json::Serializer::new(&mut json),
+    )
+    .unwrap();
+    assert_eq!(b"\"km\"", &json[..]);
+
+    let unit: length::Units =
+        serde_abbreviation::deserialize(&mut serde_json::Deserializer::from_str("\"meters\""))
+            .unwrap();
+
+    assert_eq!(length::Units::meter, unit);
+    assert!(serde_abbreviation::deserialize::<length::Units, _>(
+        &mut serde_json::Deserializer::from_str("\"kg\"")
+    )
+    .is_err());
+}
+
 storage_types! {
     use crate::tests::*;
 
@@ -30,8 +30,77 @@ storage_types! {
         Test::assert_eq(&V::one(), &m1.get::<kilogram>());
     }
 
+    #[test]
+    fn new_in() {
+        use crate::tests::length::Units;
+        use crate::tests::thermodynamic_temperature::Units as T;
+
+        let v = V::from_f64(32.0).unwrap();
+
+        Test::assert_eq(&k::Length::new::<kilometer>(V::one()),
+            &k::Length::new_in(Units::kilometer, V::one()));
+        Test::assert_eq(&k::Length::new::<meter>(V::one()),
+            &k::Length::new_in(Units::meter, V::one()));
+        assert_eq!(f::ThermodynamicTemperature::new::<degree_fahrenheit>(v.clone()).value,
+            f::ThermodynamicTemperature::new_in(T::degree_fahrenheit, v).value);
+    }
+
+    #[test]
+    fn get_in() {
+        use crate::tests::length::Units;
+        use crate::tests::thermodynamic_temperature::Units as T;
+
+        let l = k::Length::new::<kilometer>(V::one());
+        let t = f::ThermodynamicTemperature::new::<kelvin>(V::from_f64(300.0).unwrap());
+
+        Test::assert_eq(&l.get::<meter>(), &l.get_in(Units::meter));
+        Test::assert_eq(&V::one(), &l.get_in(Units::kilometer));
+        assert_eq!(t.get::<degree_fahrenheit>(), t.get_in(T::degree_fahrenheit));
+        assert_eq!(t.get::<kelvin>(), t.get_in(T::kelvin));
+    }
+
+    #[test]
+    fn custom_unit() {
+        use crate::registry::CustomUnit;
+        use crate::str::ParseOptions;
+        use crate::str::ParseQuantityErrorKind::UnknownUnit;
+
+        let mut registry = length::registry([None; 2]);
+        registry.register(CustomUnit::new("st", "step", "steps", 2.0).unwrap()).unwrap();
+
+        let unit = registry.find("steps").unwrap();
+        let l = f::Length::new::<meter>(V::from_f64(2.0).unwrap());
+
+        Test::assert_eq(&l, &f::Length::new_in(unit, V::one()));
+        Test::assert_eq(&V::one(), &l.get_in(unit));
+        Test::assert_eq(&l,
+            &f::Length::from_str_with_registry("1 st", ParseOptions::new(), &registry).unwrap());
+        Test::assert_eq(&l,
+            &f::Length::from_str_with_registry("2 m", ParseOptions::new(), &registry).unwrap());
+        assert_eq!(UnknownUnit,
+            f::Length::from_str_with_registry("1 pace", ParseOptions::new(), &registry)
+                .unwrap_err().kind());
+        assert_eq!(UnknownUnit, "1 st".parse::<f::Length>().unwrap_err().kind());
+    }
+
+    #[test]
+    fn units_coefficient() {
+        use crate::tests::length::Units;
+        use crate::tests::thermodynamic_temperature::Units as T;
+        use crate::{Conversion, ConstantOp};
+
+        assert!(<kilometer as Conversion<V>>::coefficient() == Units::kilometer.coefficient::<V>());
+        assert!(<meter as Conversion<V>>::coefficient() == Units::meter.coefficient::<V>());
+        assert!(<degree_fahrenheit as Conversion<V>>::constant(ConstantOp::Add)
+            == T::degree_fahrenheit.constant::<V>(ConstantOp::Add));
+        assert!(<kelvin as Conversion<V>>::constant(ConstantOp::Sub)
+            == T::kelvin.constant::<V>(ConstantOp::Sub));
+    }
+
     #[test]
     fn from_str() {
+        use crate::str::ParseQuantityErrorKind::{NoSeparator, UnknownUnit, ValueParseError};
+
         let l1 = k::Length::new::<meter>(V::one());
         let l2 = k::Length::new::<kilometer>(V::one());
         let m1 = k::Mass::new::<kilogram>(V::from_f64(1.0E3).unwrap());
@@ -44,11 +113,173 @@ storage_types! {
         Test::assert_eq(&"1000 kg".parse::<k::Mass>().unwrap(), &m1);
         Test::assert_eq(&"1.0E-3 kg".parse::<k::Mass>().unwrap(), &m2);
 
-        assert_eq!(&"1m".parse::<k::Length>(), &Err(ParseQuantityError::NoSeparator));
-        assert_eq!(&"10k m".parse::<k::Length>(), &Err(ParseQuantityError::ValueParseError));
-        assert_eq!(&"1,000 km".parse::<k::Length>(), &Err(ParseQuantityError::ValueParseError));
-        assert_eq!(&"10 s".parse::<k::Length>(), &Err(ParseQuantityError::UnknownUnit));
-        assert_eq!(&"10 kg //10,000 g".parse::<k::Length>(), &Err(ParseQuantityError::UnknownUnit));
+        assert_eq!(NoSeparator, "1m".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(ValueParseError, "10k m".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(ValueParseError, "1,000 km".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "10 s".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "10 kg //10,000 g".parse::<k::Length>().unwrap_err().kind());
+    }
+
+    #[test]
+    fn from_str_error_truncated() {
+        let s = "1 abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz";
+        let e = s.parse::<k::Length>().unwrap_err();
+
+        assert_eq!(cfg!(not(feature = "std")), e.is_truncated());
+        assert_eq!("abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz", &s[e.span()]);
+        assert!(s[e.span()].starts_with(e.unit().unwrap()));
+        assert_eq!(0, e.suggestions().count());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_str_error() {
+        let e = "1m".parse::<k::Length>().unwrap_err();
+
+        assert_eq!(0..2, e.span());
+        assert_eq!("length", e.quantity());
+        assert_eq!("no space between quantity and units", e.to_string());
+
+        let e = "10k m".parse::<k::Length>().unwrap_err();
+
+        assert_eq!(0..3, e.span());
+        assert_eq!(None, e.unit());
+        assert_eq!(Some(&*"10k".parse::<V>().unwrap_err().to_string()), e.value_error());
+
+        let e = "10  kmh ".parse::<k::Length>().unwrap_err();
+
+        assert_eq!(4..7, e.span());
+        assert_eq!(Some("kmh"), e.unit());
+        assert_eq!(None, e.value_error());
+        assert_eq!(None, e.other_quantity());
+        assert_eq!(vec!["km", "m"], e.suggestions().collect::<Vec<_>>());
+        assert_eq!("unrecognized unit of measure `kmh` for length; did you mean `km` or `m`",
+            e.to_string());
+
+        let e = "10 metrs".parse::<k::Length>().unwrap_err();
+
+        assert_eq!(vec!["meters", "meter"], e.suggestions().collect::<Vec<_>>());
+
+        let e = "10 kelvin".parse::<k::Length>().unwrap_err();
+
+        assert_eq!(Some("thermodynamic temperature"), e.other_quantity());
+        assert_eq!(0, e.suggestions().count());
+        assert_eq!("unrecognized unit of measure `kelvin` for length; `kelvin` is a unit of \
+            thermodynamic temperature", e.to_string());
+    }
+
+    #[test]
+    fn from_str_with_options() {
+        use crate::str::ParseQuantityErrorKind::{NoSeparator, UnknownUnit, ValueParseError};
+
+        let l1 = k::Length::new::<meter>(V::from_f64(5.0).unwrap());
+        let l2 = k::Length::new::<kilometer>(V::from_f64(1.5).unwrap());
+        let whitespace = ParseOptions::new().separator(Separator::Whitespace);
+        let optional = ParseOptions::new().separator(Separator::Optional);
+        let unit_first = optional.unit_first(true);
+        let trailing = ParseOptions::new().trailing(Trailing::Ignore);
+
+        assert_eq!(ParseOptions::new(), ParseOptions::default());
+        Test::assert_eq(&k::Length::from_str_with("5 m", ParseOptions::new()).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with(" 5  m ", whitespace).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5\u{a0}m", whitespace).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5\u{2009}m", whitespace).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5\u{202f}m", whitespace).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5m", optional).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5 m", optional).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5meters", optional).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("1.5km", optional).unwrap(), &l2);
+        Test::assert_eq(&k::Length::from_str_with("m 5", unit_first).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("m5", unit_first).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5 m; 6 m", trailing).unwrap(), &l1);
+        Test::assert_eq(&k::Length::from_str_with("5 m measured", trailing).unwrap(), &l1);
+        Test::assert_eq(&k::ThermodynamicTemperature::from_str_with("5°F", optional).unwrap(),
+            &k::ThermodynamicTemperature::new::<degree_fahrenheit>(V::from_f64(5.0).unwrap()));
+
+        assert_eq!(NoSeparator, k::Length::from_str_with("5m", whitespace).unwrap_err().kind());
+        assert_eq!(ValueParseError,
+            k::Length::from_str_with("m 5", optional).unwrap_err().kind());
+        assert_eq!(UnknownUnit,
+            k::Length::from_str_with("5 m; 6 m", ParseOptions::new()).unwrap_err().kind());
+        assert_eq!(UnknownUnit, k::Length::from_str_with("5 mx", trailing).unwrap_err().kind());
+    }
+
+    #[test]
+    fn from_str_unit_expression() {
+        use crate::str::ParseQuantityErrorKind::UnknownUnit;
+
+        let l1 = k::Length::new::<meter>(V::one());
+        let l2 = k::Length::new::<kilometer>(V::one());
+
+        Test::assert_approx_eq(&"1 km·kg/kg".parse::<k::Length>().unwrap(), &l2);
+        Test::assert_approx_eq(&"1 kg * km / kg".parse::<k::Length>().unwrap(), &l2);
+        Test::assert_approx_eq(&"1 (km)".parse::<k::Length>().unwrap(), &l2);
+        Test::assert_approx_eq(&"1000 m²/km".parse::<k::Length>().unwrap(), &l1);
+        Test::assert_approx_eq(&"1000 m^2 km^-1".parse::<k::Length>().unwrap(), &l1);
+        Test::assert_approx_eq(&"1 meters kilograms/kg".parse::<k::Length>().unwrap(), &l1);
+        Test::assert_approx_eq(&"1 m K⁻¹·K".parse::<k::Length>().unwrap(), &l1);
+
+        assert_eq!(UnknownUnit, "1 m/kg".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "1 m²".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "1 m/".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "1 m·(kg".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, "1 m·°F/K".parse::<k::Length>().unwrap_err().kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_str_unit_expression_depth() {
+        use crate::str::ParseQuantityErrorKind::UnknownUnit;
+
+        let nested = |depth| format!("1 {}km{}", "(".repeat(depth), ")".repeat(depth));
+
+        Test::assert_approx_eq(&nested(16).parse::<k::Length>().unwrap(),
+            &k::Length::new::<kilometer>(V::one()));
+        assert_eq!(UnknownUnit, nested(17).parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, nested(200_000).parse::<k::Length>().unwrap_err().kind());
+    }
+
+    #[test]
+    fn from_str_with_prefixes() {
+        use crate::str::ParseQuantityErrorKind::UnknownUnit;
+
+        let options = ParseOptions::new().prefixes(true);
+        let l1 = k::Length::new::<kilometer>(V::from_f64(1.0E3).unwrap());
+        let l2 = k::Length::new::<meter>(V::one() / V::from_f64(1.0E3).unwrap());
+
+        Test::assert_approx_eq(&k::Length::from_str_with("1 Mm", options).unwrap(), &l1);
+        Test::assert_approx_eq(&k::Length::from_str_with("1 megameter", options).unwrap(), &l1);
+        Test::assert_approx_eq(&k::Length::from_str_with("1 megameters", options).unwrap(), &l1);
+        Test::assert_approx_eq(&k::Length::from_str_with("1000 mm·kg/kg", options).unwrap(),
+            &k::Length::new::<meter>(V::one()));
+        Test::assert_approx_eq(&k::Length::from_str_with("1000 µm", options).unwrap(), &l2);
+
+        assert_eq!(UnknownUnit, "1 Mm".parse::<k::Length>().unwrap_err().kind());
+        assert_eq!(UnknownUnit, k::Length::from_str_with("1 kkm", options).unwrap_err().kind());
+        assert_eq!(UnknownUnit, k::Length::from_str_with("1 Kim", options).unwrap_err().kind());
+        assert_eq!(UnknownUnit, k::Length::from_str_with("1 Mkg", options).unwrap_err().kind());
+        assert_eq!(UnknownUnit, k::Mass::from_str_with("1 mkg", options).unwrap_err().kind());
+        assert_eq!(UnknownUnit,
+            k::Mass::from_str_with("1 millikilogram", options).unwrap_err().kind());
+        assert_eq!(UnknownUnit,
+            k::ThermodynamicTemperature::from_str_with("1 m°F", options).unwrap_err().kind());
+    }
+
+    #[test]
+    fn from_str_sum() {
+        use crate::str::ParseQuantityErrorKind::{NoSeparator, UnknownUnit, ValueParseError};
+
+        let l = f::Length::new::<meter>(V::from_f64(3.0).unwrap());
+
+        Test::assert_eq(&f::Length::from_str_sum("1 m 2 m").unwrap(), &l);
+        Test::assert_eq(&f::Length::from_str_sum("1m 2meters").unwrap(), &l);
+        Test::assert_eq(&f::Length::from_str_sum("3 m").unwrap(), &l);
+
+        assert_eq!(NoSeparator, f::Length::from_str_sum("").unwrap_err().kind());
+        assert_eq!(NoSeparator, f::Length::from_str_sum("1 m 2").unwrap_err().kind());
+        assert_eq!(ValueParseError, f::Length::from_str_sum("x m 1 m").unwrap_err().kind());
+        assert_eq!(UnknownUnit, f::Length::from_str_sum("1 m 2 kg").unwrap_err().kind());
+        assert_eq!(6..8, f::Length::from_str_sum("1 m 2 kg").unwrap_err().span());
     }
 
     #[cfg(feature = "autoconvert")]
@@ -224,6 +455,20 @@ mod fmt {
             let s2 = &format!("{}", l.clone().into_format_args(meter, DisplayStyle::Abbreviation));
             assert_eq!(s2.parse::<f::Length>(), Ok(l.clone()));
         }
+
+        #[test]
+        fn format_args_in() {
+            use crate::tests::length::Units;
+
+            let l = f::Length::new::<kilometer>(V::one());
+
+            for &style in &[DisplayStyle::Abbreviation, DisplayStyle::Description] {
+                assert_eq!(format!("{}", l.clone().into_format_args(meter, style)),
+                    format!("{}", l.clone().into_format_args_in(Units::meter, style)));
+                assert_eq!(format!("{:?}", l.clone().into_format_args(kilometer, style)),
+                    format!("{:?}", l.clone().into_format_args_in(Units::kilometer, style)));
+            }
+        }
     }
 
     mod float {
@@ -233,6 +478,7 @@ mod fmt {
             use crate::tests::*;
 
             mod f { Q!(crate::tests, super::V); }
+            mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
 
             quickcheck! {
                 #[allow(trivial_casts)]
@@ -246,6 +492,232 @@ mod fmt {
                 }
             }
 
+            #[test]
+            fn custom_unit() {
+                use crate::registry::CustomUnit;
+
+                let mut registry = length::registry([None; 1]);
+                registry.register(CustomUnit::new("st", "step", "steps", 2.0).unwrap()).unwrap();
+
+                let unit = registry.find("st").unwrap();
+                let l = f::Length::new::<meter>(V::one());
+
+                assert_eq!("0.5 st",
+                    format!("{}", l.into_format_args_in(unit, DisplayStyle::Abbreviation)));
+                assert_eq!("0.5 steps",
+                    format!("{}", l.into_format_args_in(unit, DisplayStyle::Description)));
+                assert_eq!("1 step", format!("{}",
+                    (l * V::from_f64(2.0).unwrap()).into_format_args_in(unit,
+                        DisplayStyle::Description)));
+            }
+
+            #[test]
+            fn sum() {
+                use crate::RoundingMode;
+                use crate::tests::length::Units;
+
+                let units = [Units::kilometer, Units::meter];
+                let sum = |v: V, style, rounding, precision: Option<usize>| {
+                    let a = f::Length::new::<meter>(v).into_sum_format_args(&units, style,
+                        rounding);
+
+                    match precision {
+                        Some(p) => format!("{:.*}", p, a),
+                        None => format!("{}", a),
+                    }
+                };
+                let a = DisplayStyle::Abbreviation;
+                let d = DisplayStyle::Description;
+
+                assert_eq!("1 km 5 m", sum(1_005.0, a, RoundingMode::HalfEven, None));
+                assert_eq!("1 kilometer 5 meters", sum(1_005.0, d, RoundingMode::HalfEven, None));
+                assert_eq!("2 kilometers 1 meter", sum(2_001.0, d, RoundingMode::HalfEven, None));
+                assert_eq!("1 km 6 m", sum(1_005.5, a, RoundingMode::HalfEven, None));
+                assert_eq!("1 km 6 m", sum(1_006.5, a, RoundingMode::HalfEven, None));
+                assert_eq!("1 km 5 m", sum(1_005.5, a, RoundingMode::Trunc, None));
+                assert_eq!("1 km 5.5 m", sum(1_005.5, a, RoundingMode::Trunc, Some(1)));
+                assert_eq!("1 km 0 m", sum(999.9, a, RoundingMode::HalfEven, None));
+                assert_eq!("1 km 1 m", sum(1_000.1, a, RoundingMode::Ceil, None));
+                assert_eq!("-1 km 5 m", sum(-1_005.0, a, RoundingMode::HalfEven, None));
+                assert_eq!("-1 km 5 m", sum(-1_005.5, a, RoundingMode::Trunc, None));
+                assert_eq!("-1 km 6 m", sum(-1_005.5, a, RoundingMode::Floor, None));
+                assert_eq!("-1 km 5 m", sum(-1_005.5, a, RoundingMode::Ceil, None));
+                assert_eq!("0 km 0 m", sum(-0.1, a, RoundingMode::HalfEven, None));
+                assert_eq!("", format!("{}", f::Length::new::<meter>(1.0)
+                    .into_sum_format_args(&[], a, RoundingMode::HalfEven)));
+            }
+
+            #[test]
+            fn base() {
+                use crate::fmt::Notation::*;
+
+                let l = f::Length::new::<meter>(2.0);
+                let m = f::Mass::new::<kilogram>(4.0);
+                let t = f::ThermodynamicTemperature::new::<kelvin>(1.0);
+
+                assert_eq!("8 m·kg", format!("{}", (l * m).into_base_format_args(Superscript)));
+                assert_eq!("8 m*kg", format!("{}", (l * m).into_base_format_args(Ascii)));
+                assert_eq!("8 m·kg", format!("{}", (l * m).into_base_format_args(Fraction)));
+                assert_eq!("0.5 m·kg⁻¹·K⁻¹",
+                    format!("{}", (l / (m * t)).into_base_format_args(Superscript)));
+                assert_eq!("0.5 m/(kg*K)",
+                    format!("{}", (l / (m * t)).into_base_format_args(Ascii)));
+                assert_eq!("0.5 m/(kg·K)",
+                    format!("{}", (l / (m * t)).into_base_format_args(Fraction)));
+                assert_eq!("1 kg/m²", format!("{}", (m / (l * l)).into_base_format_args(Fraction)));
+                assert_eq!("1 kg/m^2", format!("{}", (m / (l * l)).into_base_format_args(Ascii)));
+                assert_eq!("0.25 1/m²",
+                    format!("{}", (l / (l * l * l)).into_base_format_args(Fraction)));
+                assert_eq!("0.25 m⁻²",
+                    format!("{}", (l / (l * l * l)).into_base_format_args(Superscript)));
+                assert_eq!("1", format!("{}", (l / l).into_base_format_args(Fraction)));
+                assert_eq!("8.0 m·kg",
+                    format!("{:.1}", (l * m).into_base_format_args(Superscript)));
+                assert_eq!("0.008 km·kg",
+                    format!("{}", (l * m).into_base_format_args_in::<k::Units>(Superscript)));
+
+                let q = Quantity::<Q<typenum::P12, Z0, N1>, U<V>, V> {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: 1.0,
+                };
+
+                assert_eq!("1 m¹²·K⁻¹", format!("{}", q.into_base_format_args(Superscript)));
+                assert_eq!("1 m^12/K", format!("{}", q.into_base_format_args(Ascii)));
+            }
+
+            #[test]
+            fn markup() {
+                use crate::fmt::Prefixes;
+                use crate::RoundingMode;
+                use crate::tests::length::Units;
+
+                let l = f::Length::new::<meter>(1_500.0);
+                let t = f::ThermodynamicTemperature::new::<degree_fahrenheit>(32.0);
+                let format = |style| format!("{:.1}", t.into_format_args(degree_fahrenheit, style));
+
+                assert_eq!(r"\qty{1500}{\meter}",
+                    format!("{}", l.into_format_args(meter, DisplayStyle::Siunitx)));
+                assert_eq!(r"\qty{1.5}{\kilo\meter}",
+                    format!("{}", l.into_format_args(kilometer, DisplayStyle::Siunitx)));
+                assert_eq!(r"1.5\,\mathrm{km}",
+                    format!("{}", l.into_format_args(kilometer, DisplayStyle::Latex)));
+                assert_eq!("1.5&nbsp;km",
+                    format!("{}", l.into_format_args(kilometer, DisplayStyle::Html)));
+                assert_eq!(
+                    "<mrow><mn>1.5</mn><mo>&#x2062;</mo><mi mathvariant=\"normal\">km</mi></mrow>",
+                    format!("{}", l.into_format_args(kilometer, DisplayStyle::MathMl)));
+                assert_eq!(r"\qty{32.0}{\degree F}", format(DisplayStyle::Siunitx));
+                assert_eq!(r"32.0\,\mathrm{{}^{\circ}F}", format(DisplayStyle::Latex));
+                assert_eq!("32.0&nbsp;°F", format(DisplayStyle::Html));
+                assert_eq!(r"\qty{1}{\kilo\meter} \qty{500}{\meter}",
+                    format!("{}", l.into_sum_format_args(&[Units::kilometer, Units::meter],
+                        DisplayStyle::Siunitx, RoundingMode::HalfEven)));
+                assert_eq!(r"\qty{1.5}{\kilo\meter}",
+                    format!("{}", l.into_prefixed_format_args(Units::meter, Prefixes::All,
+                        DisplayStyle::Siunitx)));
+            }
+
+            #[test]
+            fn localized() {
+                use crate::fmt::locale::*;
+                use crate::tests::length::Units;
+
+                fn polish(o: &Operands) -> PluralCategory {
+                    match (o.i % 10, o.i % 100) {
+                        _ if o.v != 0 => PluralCategory::Other,
+                        _ if o.i == 1 => PluralCategory::One,
+                        (2..=4, i) if !(12..=14).contains(&i) => PluralCategory::Few,
+                        _ => PluralCategory::Many,
+                    }
+                }
+
+                const POLISH: Catalog = Catalog {
+                    plural_category: polish,
+                    quantities: &[],
+                    units: &[UnitName {
+                        quantity: "length",
+                        unit: "meter",
+                        names: &[
+                            (PluralCategory::One, "metr"),
+                            (PluralCategory::Few, "metry"),
+                            (PluralCategory::Many, "metrów"),
+                            (PluralCategory::Other, "metra"),
+                        ],
+                    }],
+                    fallback: Some(&GERMAN),
+                    ..GERMAN
+                };
+
+                let l = |v: V, unit, locale: &dyn Locale, precision: Option<usize>| {
+                    let a = f::Length::new::<meter>(v).into_localized_format_args(unit, locale);
+
+                    match precision {
+                        Some(p) => format!("{:.*}", p, a),
+                        None => format!("{}", a),
+                    }
+                };
+                let m = Units::meter;
+                let km = Units::kilometer;
+
+                assert_eq!("1 meter", l(1.0, m, &ENGLISH, None));
+                assert_eq!("-1 meter", l(-1.0, m, &ENGLISH, None));
+                assert_eq!("1.0 meters", l(1.0, m, &ENGLISH, Some(1)));
+                assert_eq!("1,234,567 meters", l(1_234_567.0, m, &ENGLISH, None));
+                assert_eq!("NaN meters", l(V::nan(), m, &ENGLISH, None));
+                assert_eq!("1.500,5 Meter", l(1_500.5, m, &GERMAN, None));
+                assert_eq!("2 Kilometer", l(2_000.0, km, &GERMAN, None));
+                assert_eq!("123,00 Meter", l(123.0, m, &GERMAN, Some(2)));
+                assert_eq!("0,5\u{a0}mètre", l(0.5, m, &FRENCH, None));
+                assert_eq!("1,5\u{a0}kilomètre", l(1_500.0, km, &FRENCH, None));
+                assert_eq!("2\u{a0}mètres", l(2.0, m, &FRENCH, None));
+                assert_eq!("1\u{202f}000\u{202f}000\u{a0}mètres", l(1.0E6, m, &FRENCH, None));
+                assert_eq!("1,500.5メートル", l(1_500.5, m, &JAPANESE, None));
+                assert_eq!("1 metr", l(1.0, m, &POLISH, None));
+                assert_eq!("22 metry", l(22.0, m, &POLISH, None));
+                assert_eq!("12 metrów", l(12.0, m, &POLISH, None));
+                assert_eq!("1,5 metra", l(1.5, m, &POLISH, None));
+                assert_eq!("1,5 Kilometer", l(1_500.0, km, &POLISH, None));
+                assert_eq!("32,0 degrees Fahrenheit", format!("{:.1}",
+                    f::ThermodynamicTemperature::new::<degree_fahrenheit>(32.0)
+                        .into_localized_format_args(
+                            thermodynamic_temperature::Units::degree_fahrenheit,
+                            &GERMAN)));
+                assert_eq!("1\u{a0}kilogramme", format!("{}",
+                    f::Mass::new::<kilogram>(1.0).into_localized_format_args(
+                        mass::Units::kilogram, &FRENCH)));
+            }
+
+            #[test]
+            fn prefixed() {
+                use crate::fmt::Prefixes;
+                use crate::tests::length::Units;
+
+                let prefixed = |v: V, prefixes, style, precision: Option<usize>| {
+                    let a = f::Length::new::<meter>(v).into_prefixed_format_args(Units::meter,
+                        prefixes, style);
+
+                    match precision {
+                        Some(p) => format!("{:.*}", p, a),
+                        None => format!("{}", a),
+                    }
+                };
+                let a = DisplayStyle::Abbreviation;
+                let d = DisplayStyle::Description;
+                let e = Prefixes::Engineering;
+
+                assert_eq!("1.5 km", prefixed(1_500.0, e, a, None));
+                assert_eq!("1.5 kilometers", prefixed(1_500.0, e, d, None));
+                assert_eq!("1 kilometer", prefixed(1_000.0, Prefixes::All, d, None));
+                assert_eq!("-1.5 km", prefixed(-1_500.0, e, a, None));
+                assert_eq!("999 m", prefixed(999.0, e, a, None));
+                assert_eq!("1.00 km", prefixed(999.999, e, a, Some(2)));
+                assert_eq!("0.5 m", prefixed(0.5, e, a, None));
+                assert_eq!("0 m", prefixed(0.0, e, a, None));
+                assert_eq!("1500 m", prefixed(1_500.0, Prefixes::Only(&[Units::meter]), a, None));
+                assert_eq!("0.0005 km",
+                    prefixed(0.5, Prefixes::Only(&[Units::kilometer]), a, None));
+            }
         }
     }
 
@@ -282,6 +754,165 @@ mod fmt {
     }
 }
 
+mod checked {
+    storage_types! {
+        types: usize, u16, u32, u64, u128, isize, i16, i32, i64, i128, BigInt, BigUint;
+
+        use crate::tests::*;
+
+        mod f { Q!(crate::tests, super::V); }
+        mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
+
+        #[test]
+        fn checked_new() {
+            let v = |v: u16| V::from_u16(v).unwrap();
+
+            assert_eq!(Some(v(1_000)),
+                f::Length::checked_new::<kilometer>(v(1)).map(|l| l.get::<meter>()));
+            assert_eq!(Some(v(2)),
+                k::Length::checked_new::<meter>(v(2_000)).map(|l| l.get::<kilometer>()));
+            assert_eq!(None, k::Length::checked_new::<meter>(v(1_500)));
+        }
+
+        #[test]
+        fn checked_get() {
+            let v = |v: u16| V::from_u16(v).unwrap();
+
+            assert_eq!(Some(v(3)), f::Length::new::<meter>(v(3_000)).checked_get::<kilometer>());
+            assert_eq!(None, f::Length::new::<meter>(v(2_500)).checked_get::<kilometer>());
+            assert_eq!(Some(v(4_000)), k::Length::new::<kilometer>(v(4)).checked_get::<meter>());
+        }
+    }
+}
+
+mod rounded {
+    storage_types! {
+        types: usize, u16, u32, u64, u128, isize, i16, i32, i64, i128, BigInt, BigUint;
+
+        use crate::num::rational::Ratio;
+        use crate::tests::*;
+        use crate::{ConversionError, RoundingMode};
+
+        mod f { Q!(crate::tests, super::V); }
+        mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
+
+        #[test]
+        fn try_new() {
+            let v = |v: u16| V::from_u16(v).unwrap();
+
+            assert_eq!(Ok(v(2)),
+                k::Length::try_new::<meter>(v(2_000)).map(|l| l.get::<kilometer>()));
+            assert_eq!(Err(ConversionError::Inexact { remainder: Ratio::new(v(1), v(2)) }),
+                k::Length::try_new::<meter>(v(2_500)));
+        }
+
+        #[test]
+        fn try_get() {
+            let v = |v: u16| V::from_u16(v).unwrap();
+
+            assert_eq!(Ok(v(3)), f::Length::new::<meter>(v(3_000)).try_get::<kilometer>());
+            assert_eq!(Err(ConversionError::Inexact { remainder: Ratio::new(v(1), v(4)) }),
+                f::Length::new::<meter>(v(3_250)).try_get::<kilometer>());
+        }
+
+        #[test]
+        fn new_rounded() {
+            let test = |i: u16, mode: RoundingMode, o: u16| {
+                assert_eq!(V::from_u16(o).unwrap(),
+                    k::Length::new_rounded::<meter>(V::from_u16(i).unwrap(), mode)
+                        .get::<kilometer>());
+            };
+
+            test(1_500, RoundingMode::Trunc, 1);
+            test(1_500, RoundingMode::Floor, 1);
+            test(1_500, RoundingMode::Ceil, 2);
+            test(1_500, RoundingMode::HalfEven, 2);
+            test(2_500, RoundingMode::HalfEven, 2);
+            test(2_501, RoundingMode::HalfEven, 3);
+            test(2_000, RoundingMode::Ceil, 2);
+        }
+
+        #[test]
+        fn get_rounded() {
+            let test = |i: u16, mode: RoundingMode, o: u16| {
+                assert_eq!(V::from_u16(o).unwrap(),
+                    f::Length::new::<meter>(V::from_u16(i).unwrap())
+                        .get_rounded::<kilometer>(mode));
+            };
+
+            test(3_499, RoundingMode::HalfEven, 3);
+            test(3_500, RoundingMode::HalfEven, 4);
+            test(4_500, RoundingMode::HalfEven, 4);
+            test(4_001, RoundingMode::Floor, 4);
+            test(4_001, RoundingMode::Ceil, 5);
+        }
+    }
+}
+
+mod rounded_signed {
+    storage_types! {
+        types: isize, i16, i32, i64, i128, BigInt;
+
+        use crate::tests::*;
+        use crate::RoundingMode;
+
+        mod f { Q!(crate::tests, super::V); }
+
+        #[test]
+        fn get_rounded() {
+            let test = |i: i16, mode: RoundingMode, o: i16| {
+                assert_eq!(V::from_i16(o).unwrap(),
+                    f::Length::new::<meter>(V::from_i16(i).unwrap())
+                        .get_rounded::<kilometer>(mode));
+            };
+
+            test(-1_500, RoundingMode::Trunc, -1);
+            test(-1_500, RoundingMode::Floor, -2);
+            test(-1_500, RoundingMode::Ceil, -1);
+            test(-1_500, RoundingMode::HalfEven, -2);
+            test(-2_500, RoundingMode::HalfEven, -2);
+            test(-2_501, RoundingMode::HalfEven, -3);
+            test(-2_000, RoundingMode::Floor, -2);
+        }
+    }
+}
+
+mod checked_overflow {
+    storage_types! {
+        types: usize, u16, u32, u64, u128, isize, i16, i32, i64, i128;
+
+        use crate::tests::*;
+        use crate::RoundingMode;
+
+        mod f { Q!(crate::tests, super::V); }
+        mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
+
+        #[test]
+        fn checked_new() {
+            assert!(f::Length::checked_new::<kilometer>(V::MAX).is_none());
+            assert!(k::Length::checked_new::<kilometer>(V::MAX).is_some());
+        }
+
+        #[test]
+        fn checked_get() {
+            assert!(k::Length::new::<kilometer>(V::MAX).checked_get::<meter>().is_none());
+            assert_eq!(Some(V::MAX),
+                f::Length::new::<meter>(V::MAX).checked_get::<meter>());
+        }
+
+        #[test]
+        fn rounded_bounds() {
+            let km = V::from_u16(1_000).unwrap();
+            let floor = if V::MIN.is_zero() { V::MIN } else { V::MIN / km - V::one() };
+
+            assert_eq!(V::MAX / km + V::one(),
+                f::Length::new::<meter>(V::MAX).get_rounded::<kilometer>(RoundingMode::Ceil));
+            assert_eq!(floor,
+                k::Length::new_rounded::<meter>(V::MIN, RoundingMode::Floor).get::<kilometer>());
+        }
+    }
+}
+
 #[cfg(feature = "autoconvert")]
 mod non_big {
     storage_types! {
@@ -363,6 +994,150 @@ mod float {
         mod f { Q!(crate::tests, super::V); }
         mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
 
+        #[test]
+        fn custom_unit_constant() {
+            use crate::registry::CustomUnit;
+
+            let mut registry = thermodynamic_temperature::registry([None; 1]);
+            registry.register(CustomUnit::new("°C", "degree Celsius", "degrees Celsius", 1.0)
+                .and_then(|unit| unit.with_constant(273.15)).unwrap()).unwrap();
+
+            let unit = registry.find("°C").unwrap();
+            let t = f::ThermodynamicTemperature::new::<kelvin>(300.0);
+
+            Test::assert_approx_eq(&300.0,
+                &f::ThermodynamicTemperature::new_in(unit, 300.0 - 273.15).get::<kelvin>());
+            Test::assert_approx_eq(&(300.0 - 273.15), &t.get_in(unit));
+        }
+
+        #[cfg(all(feature = "std", feature = "serde"))]
+        #[test]
+        fn serde_object() {
+            use crate::tests::length::serde_object;
+
+            let l = k::Length::new::<meter>(1_500.0);
+            let mut json = Vec::new();
+
+            serde_object::serialize(&l, &mut serde_json::Serializer::new(&mut json)).unwrap();
+            assert_eq!(r#"{"value":1500.0,"unit":"m"}"#, String::from_utf8(json).unwrap());
+
+            let mut json = Vec::new();
+
+            serde_object::serialize_in::<kilometer, _, _, _>(&l,
+                &mut serde_json::Serializer::new(&mut json)).unwrap();
+            assert_eq!(r#"{"value":1.5,"unit":"km"}"#, String::from_utf8(json).unwrap());
+
+            let de = |json| -> Result<f::Length, _> {
+                serde_object::deserialize(&mut serde_json::Deserializer::from_str(json))
+            };
+            let strict = |json| -> Result<f::Length, _> {
+                serde_object::strict::deserialize(&mut serde_json::Deserializer::from_str(json))
+            };
+
+            Test::assert_eq(&1_500.0, &de(r#"{"unit":"km","value":1.5}"#).unwrap().value);
+            Test::assert_eq(&1_500.0, &de(r#""1.5 km""#).unwrap().value);
+            Test::assert_eq(&1_500.0, &de(r#"{"km":1.5}"#).unwrap().value);
+            Test::assert_eq(&1_500.0, &strict(r#"{"value":1.5,"unit":"km"}"#).unwrap().value);
+            Test::assert_eq(&1.5, &de("1.5").unwrap().value);
+            Test::assert_eq(&2.0, &de("2").unwrap().value);
+            assert!(strict("1.5").is_err());
+            assert!(strict("2").is_err());
+            assert!(de(r#"{"value":1.5,"unit":"kg"}"#).is_err());
+            assert!(de(r#"{"value":1.5}"#).is_err());
+            assert!(de(r#"{"unit":"km"}"#).is_err());
+            assert!(de(r#"{"value":1.5,"unit":"km","scale":2}"#).is_err());
+            assert!(de(r#"{"value":1.5,"unit":"km","unit":"m"}"#).is_err());
+            assert!(de(r#""1.5 kg""#).is_err());
+            assert!(de(r#"{"kg":1.5}"#).is_err());
+            assert!(de(r#"{"km":1.5,"m":2.0}"#).is_err());
+            assert!(de("{}").is_err());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_lenient() {
+            use crate::str::Lenient;
+
+            let de = |json| {
+                serde_json::from_str::<Lenient<f::Length>>(json).map(Lenient::into_inner)
+            };
+            let l = f::Length::new::<kilometer>(1.5);
+
+            Test::assert_eq(&l, &de(r#""1.5 km""#).unwrap());
+            Test::assert_eq(&l, &de(r#""1.5 kilometers""#).unwrap());
+            Test::assert_eq(&l, &de(r#"{"km":1.5}"#).unwrap());
+            Test::assert_eq(&l, &de(r#"{"unit":"km","value":1.5}"#).unwrap());
+            Test::assert_eq(&l, &de("1500").unwrap());
+            assert!(de(r#""1.5 kg""#).is_err());
+            assert!(de(r#"{"kg":1.5}"#).is_err());
+            assert!(de("true").is_err());
+            assert_eq!(serde_json::to_string(&l).unwrap(),
+                serde_json::to_string(&Lenient(l)).unwrap());
+        }
+
+        #[cfg(all(feature = "std", feature = "serde"))]
+        #[test]
+        fn serde_string() {
+            use crate::tests::length::serde_string;
+            use crate::tests::thermodynamic_temperature::serde_string as temperature;
+
+            let l = k::Length::new::<meter>(1_500.0);
+            let mut json = Vec::new();
+
+            serde_string::serialize(&l, &mut serde_json::Serializer::new(&mut json)).unwrap();
+            assert_eq!(r#""1500 m""#, String::from_utf8(json).unwrap());
+
+            let mut json = Vec::new();
+
+            serde_string::serialize_in::<kilometer, _, _, _>(&l,
+                &mut serde_json::Serializer::new(&mut json)).unwrap();
+            assert_eq!(r#""1.5 km""#, String::from_utf8(json).unwrap());
+
+            let de = |json| -> Result<f::Length, _> {
+                serde_string::deserialize(&mut serde_json::Deserializer::from_str(json))
+            };
+            let strict = |json| -> Result<f::Length, _> {
+                serde_string::strict::deserialize(&mut serde_json::Deserializer::from_str(json))
+            };
+
+            Test::assert_eq(&1_500.0, &de(r#""1.5 km""#).unwrap().value);
+            Test::assert_eq(&1_500.0, &strict(r#"{"value":1.5,"unit":"km"}"#).unwrap().value);
+            Test::assert_eq(&1.5, &de("1.5").unwrap().value);
+            assert!(strict("1.5").is_err());
+
+            let t: f::ThermodynamicTemperature = temperature::deserialize(
+                &mut serde_json::Deserializer::from_str(r#""32 °F""#)).unwrap();
+
+            Test::assert_approx_eq(&f::ThermodynamicTemperature::new::<degree_fahrenheit>(32.0),
+                &t);
+        }
+
+        #[cfg(feature = "schemars")]
+        #[test]
+        fn json_schema() {
+            use crate::str::Lenient;
+            use crate::tests::length::{serde_object, serde_string};
+
+            let mut generator = schemars::SchemaGenerator::default();
+            let object = serde_object::json_schema(&mut generator);
+            let string = serde_string::json_schema(&mut generator);
+            let lenient = generator.subschema_for::<Lenient<f::Length>>();
+
+            assert_eq!(Some(&serde_json::json!(["km", "m"])),
+                object.get("properties").and_then(|p| p.pointer("/unit/enum")));
+            assert_eq!(Some(&serde_json::json!(["value", "unit"])), object.get("required"));
+            assert_eq!(Some(&serde_json::json!(r"^\S+ (?:km|m)$")), string.get("pattern"));
+            assert!(lenient.get("$ref").is_some());
+
+            let lenient = generator.into_root_schema_for::<Lenient<f::Length>>();
+            let any_of = lenient.get("anyOf").and_then(|a| a.as_array()).unwrap();
+
+            assert_eq!(4, any_of.len());
+            assert_eq!(Some(&serde_json::json!("length in m")), any_of[3].get("description"));
+            assert!(serde_json::from_value::<Lenient<f::Length>>(serde_json::json!({"km": 1.5}))
+                .is_ok());
+        }
+
         #[test]
         fn floor() {
             let l1 = k::Length::new::<kilometer>(3.9999);
@@ -492,6 +1267,44 @@ mod float {
     }
 }
 
+mod signed {
+    storage_types! {
+        types: Signed;
+
+        use crate::tests::*;
+
+        mod f { Q!(crate::tests, super::V); }
+        mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
+
+        #[test]
+        fn from_str_with_sign() {
+            let l = k::Length::new::<meter>(-V::from_f64(5.0).unwrap());
+            let sign = ParseOptions::new().sign(true);
+            let lenient = ParseOptions::lenient();
+
+            Test::assert_eq(&"-5 m".parse::<k::Length>().unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("- 5 m", sign).unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("−5 m", sign).unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("+5 m", sign).unwrap(), &-l.clone());
+            Test::assert_eq(&k::Length::from_str_with("−5m", lenient).unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("m − 5", lenient).unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("m−5", lenient).unwrap(), &l);
+            Test::assert_eq(&k::Length::from_str_with("m -5", lenient).unwrap(), &l);
+
+            assert!(k::Length::from_str_with("−5 m", ParseOptions::new()).is_err());
+            assert!(k::Length::from_str_with("- 5 m", sign.separator(Separator::Optional))
+                .is_ok());
+        }
+
+        #[test]
+        fn from_str_sum_negative() {
+            let l = f::Length::new::<meter>(-V::from_f64(5.0).unwrap());
+
+            Test::assert_eq(&f::Length::from_str_sum("-2 m 3 m").unwrap(), &l);
+        }
+    }
+}
+
 #[cfg(feature = "autoconvert")]
 mod non_complex {
     storage_types! {
@@ -571,3 +1384,106 @@ mod non_complex {
         }
     }
 }
+
+mod ratio {
+    storage_types! {
+        types: Ratio;
+
+        use crate::tests::*;
+
+        mod k { Q!(crate::tests, super::V, (kilometer, kilogram, kelvin)); }
+
+        #[test]
+        fn from_str_exact() {
+            let options = ParseOptions::new().prefixes(true);
+            let l1 = k::Length::new::<meter>(V::one() / V::from_u32(1_000).unwrap());
+
+            Test::assert_eq(&k::Length::from_str_with("1 mm", options).unwrap(), &l1);
+            Test::assert_eq(&k::Length::from_str_with("1 mm·mm/m", options).unwrap(),
+                &(l1 / V::from_u32(1_000).unwrap()));
+        }
+    }
+}
+
+#[cfg(feature = "f64")]
+mod registry {
+    use crate::registry::{CustomUnit, RegistryError};
+    use crate::tests::length;
+
+    type Unit = CustomUnit<'static, length::Dimension, f64>;
+
+    #[test]
+    fn new() {
+        assert_eq!(Err(RegistryError::Invalid), Unit::new("", "pace", "paces", 0.75));
+        assert_eq!(Err(RegistryError::Invalid), Unit::new("pc", "pace", "paces", 0.0));
+        assert_eq!(Err(RegistryError::Invalid), Unit::new("pc", "pace", "paces", f64::NAN));
+        assert_eq!(
+            Err(RegistryError::Invalid),
+            Unit::new("pc", "pace", "paces", 0.75).unwrap().with_constant(f64::INFINITY)
+        );
+        assert_eq!(0.75, Unit::new("pc", "pace", "paces", 0.75).unwrap().coefficient());
+    }
+
+    #[cfg(feature = "i32")]
+    #[test]
+    fn new_unrepresentable() {
+        type Unit = CustomUnit<'static, length::Dimension, i32>;
+
+        assert_eq!(Err(RegistryError::Invalid), Unit::new("pc", "pace", "paces", 1.0e12));
+        assert_eq!(
+            Err(RegistryError::Invalid),
+            Unit::new("pc", "pace", "paces", 1.0).unwrap().with_constant(1.0e12)
+        );
+        assert!(Unit::new("pc", "pace", "paces", 2.0).is_ok());
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = length::registry([None; 2]);
+
+        assert_eq!("length", registry.quantity());
+        assert_eq!(Ok(()), registry.register(Unit::new("st", "step", "steps", 2.0).unwrap()));
+        assert_eq!(
+            Err(RegistryError::BuiltInCollision),
+            registry.register(Unit::new("m", "metre", "metres", 1.0).unwrap())
+        );
+        assert_eq!(
+            Err(RegistryError::BuiltInCollision),
+            registry.register(Unit::new("mtr", "meter", "mtrs", 1.0).unwrap())
+        );
+        assert_eq!(
+            Err(RegistryError::Collision),
+            registry.register(Unit::new("stp", "stp", "steps", 2.0).unwrap())
+        );
+        assert_eq!(Ok(()), registry.register(Unit::new("pc", "pace", "paces", 0.75).unwrap()));
+        assert_eq!(
+            Err(RegistryError::Full),
+            registry.register(Unit::new("ft", "foot", "feet", 0.3048).unwrap())
+        );
+
+        assert!(registry.units().map(|unit| unit.abbreviation()).eq(["st", "pc"]));
+    }
+
+    #[test]
+    fn find() {
+        let unit = Unit::new("st", "step", "steps", 2.0).unwrap();
+        let mut storage = [None; 1];
+        let mut registry = length::registry(&mut storage[..]);
+
+        registry.register(unit).unwrap();
+
+        assert_eq!(Some(unit), registry.find("st"));
+        assert_eq!(Some(unit), registry.find("step"));
+        assert_eq!(Some(unit), registry.find("steps"));
+        assert_eq!(None, registry.find("m"));
+        assert_eq!(None, registry.find("Step"));
+    }
+
+    #[test]
+    fn new_clears_storage() {
+        let mut storage = [Some(Unit::new("st", "step", "steps", 2.0).unwrap())];
+        let registry = length::registry(&mut storage[..]);
+
+        assert_eq!(None, registry.units().next());
+    }
+}
@@ -233,6 +233,94 @@ mod prim_int {
                     &(Length::new::<meter>((*l).clone())
                         .saturating_sub(Length::new::<meter>((*r).clone())).get::<meter>()))
             }
+
+            #[allow(trivial_casts)]
+            fn checked_add(l: A<V>, r: A<V>) -> bool {
+                l.checked_add(*r)
+                    == Length::new::<meter>(*l).checked_add(Length::new::<meter>(*r))
+                        .map(|v| v.get::<meter>())
+            }
+
+            #[allow(trivial_casts)]
+            fn checked_sub(l: A<V>, r: A<V>) -> bool {
+                l.checked_sub(*r)
+                    == Length::new::<meter>(*l).checked_sub(Length::new::<meter>(*r))
+                        .map(|v| v.get::<meter>())
+            }
+
+            #[allow(trivial_casts)]
+            fn checked_mul(l: A<V>, r: A<V>) -> bool {
+                l.checked_mul(*r)
+                    == Length::new::<meter>(*l).checked_mul(Mass::new::<kilogram>(*r))
+                        .map(|v| v.value)
+            }
+
+            #[allow(trivial_casts)]
+            fn checked_div(l: A<V>, r: A<V>) -> bool {
+                l.checked_div(*r)
+                    == Length::new::<meter>(*l).checked_div(Length::new::<meter>(*r))
+                        .map(|v| v.value)
+            }
+
+            #[allow(trivial_casts)]
+            fn checked_rem(l: A<V>, r: A<V>) -> bool {
+                l.checked_rem(*r)
+                    == Length::new::<meter>(*l).checked_rem(Length::new::<meter>(*r))
+                        .map(|v| v.get::<meter>())
+            }
+
+            #[allow(trivial_casts)]
+            fn checked_traits(l: A<V>, r: A<V>) -> bool {
+                use crate::num_traits::{CheckedAdd, CheckedSub};
+
+                l.checked_add(*r)
+                    == CheckedAdd::checked_add(&Length::new::<meter>(*l), &Length::new::<meter>(*r))
+                        .map(|v| v.get::<meter>())
+                    && l.checked_sub(*r)
+                        == CheckedSub::checked_sub(&Length::new::<meter>(*l),
+                            &Length::new::<meter>(*r)).map(|v| v.get::<meter>())
+            }
+
+            #[allow(trivial_casts)]
+            fn wrapping_add(l: A<V>, r: A<V>) -> bool {
+                l.wrapping_add(*r)
+                    == Length::new::<meter>(*l).wrapping_add(Length::new::<meter>(*r))
+                        .get::<meter>()
+            }
+
+            #[allow(trivial_casts)]
+            fn wrapping_sub(l: A<V>, r: A<V>) -> bool {
+                l.wrapping_sub(*r)
+                    == Length::new::<meter>(*l).wrapping_sub(Length::new::<meter>(*r))
+                        .get::<meter>()
+            }
+
+            #[allow(trivial_casts)]
+            fn wrapping_mul(l: A<V>, r: A<V>) -> bool {
+                l.wrapping_mul(*r)
+                    == Length::new::<meter>(*l).wrapping_mul(Length::new::<meter>(*r)).value
+            }
+
+            #[allow(trivial_casts)]
+            fn overflowing_add(l: A<V>, r: A<V>) -> bool {
+                let (v, o) = Length::new::<meter>(*l).overflowing_add(Length::new::<meter>(*r));
+
+                l.overflowing_add(*r) == (v.get::<meter>(), o)
+            }
+
+            #[allow(trivial_casts)]
+            fn overflowing_sub(l: A<V>, r: A<V>) -> bool {
+                let (v, o) = Length::new::<meter>(*l).overflowing_sub(Length::new::<meter>(*r));
+
+                l.overflowing_sub(*r) == (v.get::<meter>(), o)
+            }
+
+            #[allow(trivial_casts)]
+            fn overflowing_mul(l: A<V>, r: A<V>) -> bool {
+                let (v, o) = Length::new::<meter>(*l).overflowing_mul(Mass::new::<kilogram>(*r));
+
+                l.overflowing_mul(*r) == (v.value, o)
+            }
         }
     }
 }
@@ -245,6 +333,20 @@ mod float {
 
         Q!(crate::tests, V);
 
+        #[cfg(feature = "schemars")]
+        #[test]
+        fn json_schema() {
+            let mut generator = schemars::SchemaGenerator::default();
+            let length = generator.subschema_for::<Length>();
+            let area = generator.subschema_for::<Quantity<Q<P2, Z0, Z0>, U<V>, V>>();
+
+            assert_eq!(Some(&serde_json::json!("number")), length.get("type"));
+            assert_eq!(Some(&serde_json::json!("length in m")), length.get("description"));
+            assert_eq!(Some(&serde_json::json!("m")), length.get("x-unit"));
+            assert_eq!(Some(&serde_json::json!("value in m^2")), area.get("description"));
+            assert_eq!(Some(&serde_json::json!("m^2")), area.get("x-unit"));
+        }
+
         #[test]
         fn fp_categories() {
             assert!(!Length::new::<meter>(V::infinity()).is_finite());
@@ -258,6 +360,30 @@ mod float {
             assert!(!Length::new::<meter>(V::infinity()).is_normal());
         }
 
+        #[cfg(feature = "std")]
+        #[test]
+        fn root_negative() {
+            use crate::typenum::P6;
+
+            let v = Quantity::<Q<P3, Z0, Z0>, U<V>, V> {
+                dimension: PhantomData,
+                units: PhantomData,
+                value: -8.0,
+            };
+            let l: Quantity<Q<P1, Z0, Z0>, U<V>, V> = v.root::<P3>();
+            let a: Quantity<Q<P2, Z0, Z0>, U<V>, V> = v.powr::<P2, P3>();
+            let r: Quantity<Q<P1, Z0, Z0>, U<V>, V> = v.powr::<P2, P6>();
+
+            Test::assert_approx_eq(&-2.0, &l.value);
+            Test::assert_approx_eq(&4.0, &a.value);
+            Test::assert_approx_eq(&-2.0, &r.value);
+            assert!(Quantity::<Q<P2, Z0, Z0>, U<V>, V> {
+                dimension: PhantomData,
+                units: PhantomData,
+                value: -4.0,
+            }.powr::<P1, P2>().is_nan());
+        }
+
         quickcheck! {
             #[allow(trivial_casts)]
             fn is_nan(v: A<V>) -> bool {
@@ -360,6 +486,52 @@ mod float {
                 TestResult::from_bool(Test::eq(&v.sqrt(), &l.value))
             }
 
+            #[cfg(feature = "std")]
+            #[allow(trivial_casts)]
+            fn root(v: A<V>) -> TestResult {
+                use crate::typenum::P4;
+
+                if *v < V::zero() {
+                    return TestResult::discard();
+                }
+
+                let l: Quantity<Q<P1, Z0, Z0>, U<V>, V> = Quantity::<Q<P4, Z0, Z0>, U<V>, V> {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: *v,
+                }.root::<P4>();
+
+                TestResult::from_bool(Test::eq(&v.powf(0.25), &l.value))
+            }
+
+            #[cfg(feature = "std")]
+            #[allow(trivial_casts)]
+            fn powr(v: A<V>) -> TestResult {
+                if *v < V::zero() {
+                    return TestResult::discard();
+                }
+
+                let l: Quantity<Q<P3, Z0, Z0>, U<V>, V> = Quantity::<Q<P2, Z0, Z0>, U<V>, V> {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: *v,
+                }.powr::<P3, P2>();
+
+                TestResult::from_bool(Test::eq(&v.powf(1.5), &l.value))
+            }
+
+            #[cfg(feature = "std")]
+            #[allow(trivial_casts)]
+            fn powf(v: A<V>, e: A<V>) -> bool {
+                let r = Quantity::<Q<Z0, Z0, Z0>, U<V>, V> {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: *v,
+                }.powf(*e);
+
+                Test::eq(&v.powf(*e), &r.value)
+            }
+
             #[allow(trivial_casts)]
             fn max(l: A<V>, r: A<V>) -> bool {
                 Test::eq(&Length::new::<meter>(l.max(*r)),
@@ -737,3 +909,488 @@ mod non_complex {
         }
     }
 }
+
+mod dyn_quantity {
+    storage_types! {
+        use crate::tests::*;
+
+        type KilometerBase = dyn Units<V, length = kilometer, mass = kilogram,
+            thermodynamic_temperature = kelvin>;
+
+        Q!(crate::tests, V);
+
+        #[test]
+        fn from_quantity() {
+            let d = DynQuantity::from(Length::new::<kilometer>(V::one()));
+
+            Test::assert_eq(&V::from_f64(1000.0).unwrap(), &d.value);
+            assert_eq!([1, 0, 0], d.exponents());
+            assert_eq!(None, d.kind());
+            assert!(!d.is_dimensionless());
+
+            let d = DynQuantity::from(
+                length::Length::<KilometerBase, V>::new::<kilometer>(V::one()));
+
+            Test::assert_eq(&V::from_f64(1000.0).unwrap(), &d.value);
+        }
+
+        #[test]
+        fn try_from_dyn_quantity() {
+            let d = DynQuantity::new(V::from_f64(1000.0).unwrap(), [1, 0, 0]);
+
+            assert!(d.is::<length::Dimension>());
+            Test::assert_eq(&V::one(), &Length::try_from(d.clone()).unwrap().get::<kilometer>());
+            Test::assert_eq(&V::one(),
+                &length::Length::<KilometerBase, V>::try_from(d.clone()).unwrap().value);
+
+            let e = Mass::try_from(d.clone()).unwrap_err();
+
+            assert!(!d.is::<mass::Dimension>());
+            assert_eq!([0, 1, 0], e.expected());
+            assert_eq!([1, 0, 0], e.found());
+        }
+
+        #[test]
+        fn add_sub() {
+            let l = DynQuantity::from(Length::new::<meter>(V::one()));
+            let m = DynQuantity::from(Mass::new::<kilogram>(V::one()));
+
+            Test::assert_eq(&(V::one() + V::one()), &(l.clone() + l.clone()).unwrap().value);
+            Test::assert_eq(&V::zero(), &(l.clone() - l.clone()).unwrap().value);
+            assert!((l.clone() + m.clone()).is_err());
+            assert_eq!([1, 0, 0], (l.clone() - m).unwrap_err().expected());
+
+            let k = DynQuantity { kind: Some(crate::lib::any::TypeId::of::<u8>()), ..l.clone() };
+
+            assert_eq!(Err(DimensionError::Kind { exponents: [1, 0, 0] }), l.clone() + k.clone());
+            assert_eq!(Err(DimensionError::Kind { exponents: [1, 0, 0] }), k.clone() - l);
+            assert!((k.clone() + k).is_ok());
+        }
+
+        #[test]
+        fn mul_div() {
+            let l = DynQuantity::from(Length::new::<kilometer>(V::one()));
+            let m = DynQuantity::from(Mass::new::<kilogram>(V::one()));
+
+            assert_eq!([1, 1, 0], (l.clone() * m.clone()).exponents());
+            assert_eq!([1, -1, 0], (l.clone() / m).exponents());
+            assert!((l.clone() / l.clone()).is_dimensionless());
+            Test::assert_eq(&V::from_f64(2000.0).unwrap(),
+                &(l.clone() * V::from_f64(2.0).unwrap()).value);
+            assert_eq!([1, 0, 0], (l / V::one()).exponents());
+
+            let d = DynQuantity::new(V::one(), [i32::MAX, i32::MIN, 0]);
+
+            assert_eq!(Some([i32::MAX - 1, i32::MIN + 1, 0]), d.clone()
+                .checked_mul(DynQuantity::new(V::one(), [-1, 1, 0])).map(|d| d.exponents()));
+            assert!(d.clone().checked_mul(d.clone()).is_none());
+            assert!(d.checked_div(DynQuantity::new(V::one(), [-1, 1, 0])).is_none());
+        }
+
+        #[test]
+        #[should_panic(expected = "dimension exponent overflow")]
+        fn mul_overflow() {
+            let d = DynQuantity::new(V::one(), [i32::MAX, 0, 0]);
+
+            let _ = d.clone() * d;
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use crate::tests::*;
+
+            Q!(crate::tests, V);
+
+            #[test]
+            fn from_str() {
+                let d: DynQuantity<V> = "5 km/kg".parse().unwrap();
+
+                Test::assert_eq(&5000.0, &d.value);
+                assert_eq!([1, -1, 0], d.exponents());
+                Test::assert_eq(&2.0,
+                    &DynQuantity::<V>::with_unit(2.0, "m").unwrap().value);
+                Test::assert_eq(&1.0E6,
+                    &DynQuantity::<V>::with_unit(1.0, "km²").unwrap().value);
+                assert!(DynQuantity::<V>::with_unit(1.0, "°F").is_none());
+
+                let e = "5 kms".parse::<DynQuantity<V>>().unwrap_err();
+
+                assert_eq!(ParseQuantityErrorKind::UnknownUnit, e.kind());
+                assert_eq!(Some("km"), e.suggestions().next());
+                assert_eq!(ParseQuantityErrorKind::NoSeparator,
+                    "5".parse::<DynQuantity<V>>().unwrap_err().kind());
+            }
+
+            #[test]
+            fn quantities() {
+                let d = DynQuantity::<V>::new(1.0, [0, 0, 1]);
+
+                assert_eq!(Some("ThermodynamicTemperature"),
+                    d.quantities().next().map(QuantityInfo::name));
+                assert!((d * d).quantities().next().is_none());
+            }
+
+            #[test]
+            fn powi() {
+                let d = DynQuantity::from(Length::new::<kilometer>(2.0)).powi(2).unwrap();
+
+                Test::assert_eq(&4.0E6, &d.value);
+                assert_eq!([2, 0, 0], d.exponents());
+                assert_eq!(Some([-2, 0, 0]), d.powi(-1).map(|d| d.exponents()));
+                assert!(d.powi(i32::MAX).is_none());
+                assert!(DynQuantity::new(1.0, [i32::MIN, 0, 0]).powi(-1).is_none());
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn fmt() {
+                let d: DynQuantity<V> = "5 m/kg".parse().unwrap();
+
+                assert_eq!("5.0 m^1 kg^-1", format!("{:?}", d));
+                assert_eq!("dimension mismatch: expected m^1, found m^1 kg^-1",
+                    format!("{}", Length::try_from(d).unwrap_err()));
+                assert_eq!("dimension mismatch: expected 1, found m^1 kg^-1",
+                    format!("{}", (DynQuantity::new(1.0, [0, 0, 0]) - d).unwrap_err()));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "wire")]
+mod wire {
+    storage_types! {
+        types: PrimInt, Float;
+
+        use crate::tests::*;
+        use crate::wire::WireError;
+
+        type FahrenheitBase = dyn Units<V, length = meter, mass = kilogram,
+            thermodynamic_temperature = degree_fahrenheit>;
+
+        Q!(crate::tests, V);
+
+        #[test]
+        fn round_trip() {
+            let l = Length::new::<meter>(V::from_u8(7).unwrap());
+            let mut buffer = [0; Length::WIRE_LEN + 1];
+
+            assert_eq!(Length::WIRE_LEN, l.encode_wire(&mut buffer).unwrap());
+            assert_eq!(3, buffer[1]);
+            assert_eq!([0x01, 0x00], buffer[2..4]);
+            assert_eq!(0, buffer[4]);
+
+            let (d, len) = Length::decode_wire(&buffer).unwrap();
+
+            Test::assert_eq(&l.value, &d.value);
+            assert_eq!(Length::WIRE_LEN, len);
+        }
+
+        #[test]
+        fn errors() {
+            let l = Length::new::<meter>(V::one());
+            let mut buffer = [0; Length::WIRE_LEN];
+
+            assert_eq!(Err(WireError::BufferTooSmall(Length::WIRE_LEN)),
+                l.encode_wire(&mut buffer[1..]));
+            assert_eq!(Length::WIRE_LEN, l.encode_wire(&mut buffer).unwrap());
+            assert_eq!(Err(DecodeError::Wire(WireError::BufferTooSmall(Length::WIRE_LEN))),
+                Length::decode_wire(&buffer[..Length::WIRE_LEN - 1]).map(|(_, len)| len));
+
+            match Mass::decode_wire(&buffer) {
+                Err(DecodeError::Dimension(e)) => {
+                    assert_eq!([0, 1, 0], e.expected());
+                    assert_eq!([1, 0, 0], e.found());
+                }
+                _ => panic!("dimension mismatch expected"),
+            }
+
+            buffer[0] ^= 0x30;
+
+            assert_eq!(Err(DecodeError::Wire(WireError::Version(2))),
+                Length::decode_wire(&buffer).map(|(_, len)| len));
+
+            let t = thermodynamic_temperature::ThermodynamicTemperature::<FahrenheitBase, V>::new::<
+                kelvin>(V::one());
+
+            assert_eq!(Err(WireError::Scale), t.encode_wire(&mut [0; 16]));
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use crate::tests::*;
+
+            type KilometerBase = dyn Units<V, length = kilometer, mass = kilogram,
+                thermodynamic_temperature = kelvin>;
+
+            Q!(crate::tests, V);
+
+            #[test]
+            fn rescale() {
+                let l = length::Length::<KilometerBase, V>::new::<kilometer>(1.5);
+                let mut buffer = [0; Length::WIRE_LEN];
+
+                assert_eq!(Length::WIRE_LEN, l.encode_wire(&mut buffer).unwrap());
+                assert_eq!(3, buffer[4]);
+                Test::assert_eq(&1500.0, &Length::decode_wire(&buffer).unwrap().0.value);
+
+                let a = Length::new::<meter>(2.0) * Length::new::<meter>(3.0);
+                let mut buffer = [0; Length::WIRE_LEN];
+
+                assert_eq!(Length::WIRE_LEN, a.encode_wire(&mut buffer).unwrap());
+                assert_eq!(0x02, buffer[2]);
+                Test::assert_eq(&6.0E-6,
+                    &Quantity::<Q<P2, Z0, Z0>, KilometerBase, V>::decode_wire(&buffer)
+                        .unwrap().0.value);
+            }
+        }
+    }
+
+    mod prim_int {
+        storage_types! {
+            types: PrimInt;
+
+            use crate::tests::*;
+            use crate::wire::WireError;
+
+            Q!(crate::tests, V);
+
+            #[test]
+            fn scale() {
+                let mut buffer = [0; Length::WIRE_LEN];
+
+                assert_eq!(Length::WIRE_LEN,
+                    Length::new::<meter>(V::from_u8(10).unwrap()).encode_wire(&mut buffer).unwrap());
+                buffer[4] = 0xFF;
+                Test::assert_eq(&V::one(), &Length::decode_wire(&buffer).unwrap().0.value);
+
+                buffer[4] = 0xFE;
+                assert_eq!(Err(DecodeError::Wire(WireError::Value)),
+                    Length::decode_wire(&buffer).map(|(_, len)| len));
+
+                buffer[4] = 0x7F;
+                assert_eq!(Err(DecodeError::Wire(WireError::Value)),
+                    Length::decode_wire(&buffer).map(|(_, len)| len));
+
+                buffer[4] = 0x80;
+                assert_eq!(Err(DecodeError::Wire(WireError::Value)),
+                    Length::decode_wire(&buffer).map(|(_, len)| len));
+
+                assert_eq!(Length::WIRE_LEN,
+                    Length::new::<meter>(V::zero()).encode_wire(&mut buffer).unwrap());
+                buffer[4] = 0x80;
+                Test::assert_eq(&V::zero(), &Length::decode_wire(&buffer).unwrap().0.value);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "f64", feature = "i32"))]
+    #[test]
+    fn fractional() {
+        use crate::tests::*;
+        use crate::wire::WireError;
+
+        type F = length::Length<U<f64>, f64>;
+        type I = length::Length<U<i32>, i32>;
+
+        let mut buffer = [0; F::WIRE_LEN];
+
+        assert_eq!(F::WIRE_LEN, F::new::<meter>(2.7).encode_wire(&mut buffer).unwrap());
+        assert_eq!(
+            Err(DecodeError::Wire(WireError::Value)),
+            I::decode_wire(&buffer).map(|(_, len)| len)
+        );
+        assert_eq!(F::WIRE_LEN, F::new::<meter>(-3.0).encode_wire(&mut buffer).unwrap());
+        assert_eq!(-3, I::decode_wire(&buffer).unwrap().0.value);
+    }
+
+    #[cfg(all(feature = "f32", feature = "f64"))]
+    #[test]
+    fn storage() {
+        use crate::tests::*;
+
+        let mut buffer = [0; 16];
+        let len =
+            length::Length::<U<f32>, f32>::new::<kilometer>(2.5).encode_wire(&mut buffer).unwrap();
+        let (l, decoded_len) = length::Length::<U<f64>, f64>::decode_wire(&buffer).unwrap();
+
+        assert_eq!(len, decoded_len);
+        assert_eq!(2500.0, l.value);
+    }
+}
+
+mod reflection {
+    use crate::tests::*;
+
+    #[test]
+    fn runtime_dimension() {
+        assert_eq!([1, 0, 0], length::Dimension::exponents());
+        assert_eq!([0, 0, 1], thermodynamic_temperature::Dimension::exponents());
+        assert_eq!(None, mass::Dimension::kind());
+        assert_eq!(Some("Length"), length::Dimension::quantity().map(QuantityInfo::name));
+        assert!(<Q<P1, P1, Z0> as RuntimeDimension>::quantity().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn quantities() {
+        let names: Vec<_> = QUANTITIES.iter().map(QuantityInfo::name).collect();
+
+        assert_eq!(vec!["Length", "Mass", "ThermodynamicTemperature"], names);
+        assert_eq!("thermodynamic temperature", QUANTITIES[2].description());
+        assert_eq!([0, 1, 0], QUANTITIES[1].exponents());
+        assert_eq!(None, QUANTITIES[1].kind());
+        assert_eq!(
+            vec![DynUnit::Length(length::Units::kilometer), DynUnit::Length(length::Units::meter)],
+            QUANTITIES[0].units().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn quantities_with_exponents() {
+        let mut quantities = crate::tests::quantities_with_exponents([0, 1, 0]);
+
+        assert_eq!(Some("Mass"), quantities.next().map(QuantityInfo::name));
+        assert!(quantities.next().is_none());
+        assert!(crate::tests::quantities_with_exponents([2, 0, 0]).next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dyn_unit() {
+        let unit = DynUnit::from(thermodynamic_temperature::Units::degree_fahrenheit);
+
+        assert_eq!("degree_fahrenheit", unit.identifier());
+        assert_eq!("°F", unit.abbreviation());
+        assert_eq!("degree Fahrenheit", unit.singular());
+        assert_eq!("degrees Fahrenheit", unit.plural());
+        assert_eq!(None, unit.markup());
+        assert_eq!(Some(r"\meter"), DynUnit::Length(length::Units::meter).markup());
+        assert_eq!("°F", format!("{}", unit));
+    }
+}
+
+mod convert {
+    use crate::tests::*;
+
+    #[test]
+    fn convert_value() {
+        use crate::tests::convert_value;
+
+        assert_eq!(Ok(5000.0), convert_value(5.0, "km", "m"));
+        assert_eq!(Ok(0.5), convert_value(500.0, "meters", "kilometer"));
+        assert_eq!(Ok(2000.0), convert_value(2.0, "km/kg", "m/kg"));
+        assert_eq!(Ok(0.0), convert_value(-459.67, "°F", "K"));
+        assert_eq!(Ok(212.0), convert_value(373.15, "K", "degrees Fahrenheit").map(f64::round));
+        assert!(convert_value(1.0, "°F/kg", "K/kg").is_err());
+    }
+
+    #[test]
+    fn convert_value_error() {
+        use crate::tests::convert_value;
+
+        match convert_value(1.0, "km", "kg") {
+            Err(ConvertError::Dimension(e)) => {
+                assert_eq!([0, 1, 0], e.expected());
+                assert_eq!([1, 0, 0], e.found());
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        match convert_value(1.0, "km", "kms") {
+            Err(ConvertError::Parse(e)) => {
+                assert_eq!(Some("kms"), e.unit());
+                assert_eq!(0..3, e.span());
+                assert_eq!(Some("km"), e.suggestions().next());
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn convert() {
+        use crate::tests::convert;
+
+        assert_eq!(Ok(5000.0), convert("5 km", "m"));
+        assert_eq!(Ok(-5000.0), convert("-5 km", "m"));
+        assert_eq!(Ok(0.0), convert("-459.67 °F", "K"));
+        assert!(matches!(convert("5 km", "kg"), Err(ConvertError::Dimension(_))));
+
+        match convert("5 kms", "m") {
+            Err(ConvertError::Parse(e)) => {
+                assert_eq!(ParseQuantityErrorKind::UnknownUnit, e.kind());
+                assert_eq!(2..5, e.span());
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        match convert("x km", "m") {
+            Err(ConvertError::Parse(e)) => {
+                assert_eq!(ParseQuantityErrorKind::ValueParseError, e.kind())
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn convert_error_display() {
+        use crate::tests::convert;
+
+        assert_eq!(
+            "dimension mismatch: expected kg^1, found m^1",
+            convert("5 km", "kg").unwrap_err().to_string()
+        );
+    }
+}
+
+mod cast {
+    storage_types! {
+        types: Float;
+
+        use crate::tests::*;
+
+        type KilometerBase = dyn Units<V, length = kilometer, mass = kilogram,
+            thermodynamic_temperature = kelvin>;
+
+        Q!(crate::tests, V);
+
+        #[test]
+        fn into_units() {
+            let l = Length::new::<meter>(1500.0).into_units::<KilometerBase>();
+
+            Test::assert_eq(&1.5, &l.value);
+            Test::assert_eq(&1500.0, &l.into_units::<U<V>>().value);
+        }
+    }
+
+    #[cfg(all(feature = "f32", feature = "f64"))]
+    #[test]
+    fn cast() {
+        use crate::tests::*;
+
+        let l: length::Length<U<f64>, f64> =
+            length::Length::<U<f32>, f32>::new::<kilometer>(2.5).cast();
+
+        assert_eq!(2500.0, l.value);
+        assert_eq!(Some(2500.0), l.try_cast::<f32>().map(|l| l.get::<meter>()));
+    }
+
+    #[cfg(all(feature = "f64", feature = "i32"))]
+    #[test]
+    fn try_cast() {
+        use crate::tests::*;
+
+        let l: Option<length::Length<U<i32>, i32>> =
+            length::Length::<U<f64>, f64>::new::<meter>(3.0).try_cast();
+
+        assert_eq!(Some(3), l.map(|l| l.value));
+        assert!(length::Length::<U<f64>, f64>::new::<meter>(1.0E10).try_cast::<i32>().is_none());
+        assert!(length::Length::<U<i32>, i32>::new::<meter>(7).try_cast::<f64>().is_some());
+    }
+}
@@ -14,16 +14,25 @@
 /// * `$unit`: Unit name (e.g. `meter`, `foot`).
 /// * `$coefficient`: Conversion coefficient from the unit to the base unit of the quantity (e.g.
 ///   `3.048_E-1` to convert `foot` to `meter`, `1.0_E0` to convert `celsius` to `kelvin`).
+/// * `$exact`: Optional exact rational coefficient, an [`ExactFactor`](crate::ExactFactor)
+///   constant expression, used verbatim by integer, rational, and big rational underlying storage
+///   types instead of `$coefficient` (e.g. `ExactFactor::new(3_048, 10_000)` to convert `foot` to
+///   `meter`). Floating point underlying storage types always use `$coefficient`.
 /// * `$constant`: Optional conversion constant factor from the unit to the base unit of the
 ///   quantity (e.g. `273.15_E0` to convert `celsius` to `kelvin`). Note that using a unit with a
 ///   non-zero constant factor is not currently supported as a base unit.
+/// * `$exact_constant`: Optional exact rational constant, an
+///   [`ExactFactor`](crate::ExactFactor) constant expression, used like `$exact`.
 /// * `$abbreviation`: Unit abbreviation (e.g. `"m"`).
 /// * `$singular`: Singular unit description (e.g. `"meter"`).
 /// * `$plural`: Plural unit description (e.g. `"meters"`).
+/// * `$markup`: Optional [siunitx][siunitx] unit markup (e.g. `r"\kilo\meter"`) used by
+///   [`DisplayStyle::Siunitx`](crate::fmt::DisplayStyle::Siunitx). When not specified the markup
+///   is generated from the abbreviation.
 ///
-/// An example invocation is given below to add kilometers to length in a meter-kilogram-second
-/// system. The `#[macro_use]` attribute must be used when including the `uom` crate to make the
-/// `unit!` macro available.
+/// An example invocation is given below to add kilometers and miles, with an exact coefficient, to
+/// length in a meter-kilogram-second system. The `#[macro_use]` attribute must be used when
+/// including the `uom` crate to make the `unit!` macro available.
 ///
 /// ```
 /// #[macro_use]
@@ -91,6 +100,7 @@
 ///     quantity: crate::mks::length;
 ///
 ///     @kilometer: 1.0E-03; "km", "kilometer", "kilometers";
+///     @mile: 1.609_344E3 => uom::ExactFactor::new(1_609_344, 1_000); "mi", "mile", "miles";
 /// }
 /// #     }
 /// # }
@@ -99,21 +109,24 @@
 /// [si]: https://jcgm.bipm.org/vim/en/1.16.html
 /// [measurement]: https://jcgm.bipm.org/vim/en/1.9.html
 /// [pr]: https://github.com/iliekturtles/uom/pulls
+/// [siunitx]: https://ctan.org/pkg/siunitx
 #[macro_export]
 macro_rules! unit {
     (
         system: $system:path;
         quantity: $quantity:path;
 
-        $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(, $constant:expr)?;
-            $abbreviation:expr, $singular:expr, $plural:expr;)+
+        $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(=> $exact:expr)?
+            $(, $constant:expr $(=> $exact_constant:expr)?)?;
+            $abbreviation:expr, $singular:expr, $plural:expr $(, $markup:expr)?;)+
     ) => {
         use $system as __system;
         use $quantity as __quantity;
         use __quantity::{Conversion, Unit};
 
-        unit_units!($($(#[$unit_attr])* @$unit: $coefficient $(, $constant)?;
-            $abbreviation, $singular, $plural;)+);
+        unit_units!($($(#[$unit_attr])* @$unit: $coefficient $(=> $exact)?
+            $(, $constant $(=> $exact_constant)?)?;
+            $abbreviation, $singular, $plural $(, $markup)?;)+);
     };
 }
 
@@ -121,8 +134,9 @@ macro_rules! unit {
 #[doc(hidden)]
 macro_rules! unit_units {
     (
-        $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(, $constant:expr)?;
-            $abbreviation:expr, $singular:expr, $plural:expr;)+
+        $($(#[$unit_attr:meta])* @$unit:ident: $coefficient:expr $(=> $exact:expr)?
+            $(, $constant:expr $(=> $exact_constant:expr)?)?;
+            $abbreviation:expr, $singular:expr, $plural:expr $(, $markup:expr)?;)+
     ) => {
         $(unit_unit!($(#[$unit_attr])* @$unit $plural);
 
@@ -141,6 +155,11 @@ macro_rules! unit_units {
             fn plural() -> &'static str {
                 $plural
             }
+
+            #[inline(always)]
+            fn markup() -> Option<&'static str> {
+                None $(.or(Some($markup)))?
+            }
         }
 
         impl Unit for $unit {})+
@@ -190,6 +209,8 @@ macro_rules! unit_units {
                 <T as $crate::num::FromPrimitive>::from_f64(value).unwrap()
             }
 
+            unit_exact_fns!(ratio V);
+
             $(impl $crate::Conversion<V> for super::$unit {
                 type T = T;
 
@@ -197,13 +218,14 @@ macro_rules! unit_units {
                 #[allow(clippy::eq_op)]
                 #[allow(clippy::approx_constant)]
                 fn coefficient() -> Self::T {
-                    from_f64($coefficient)
+                    exact(unit_exact!($($exact)?)).unwrap_or_else(|| from_f64($coefficient))
                 }
 
                 #[inline(always)]
                 #[allow(unused_variables)]
                 fn constant(op: $crate::ConstantOp) -> Self::T {
-                    from_f64(unit_constant!(op $($constant)?))
+                    exact(unit_exact!($($($exact_constant)?)?))
+                        .unwrap_or_else(|| from_f64(unit_constant!(op $($constant)?)))
                 }
             }
 
@@ -215,6 +237,10 @@ macro_rules! unit_units {
                 fn is_valid() -> bool {
                     use $crate::num::{FromPrimitive, ToPrimitive};
 
+                    if let Some(conversion) = exact(unit_exact!($($exact)?)) {
+                        return conversion.numer() >= conversion.denom();
+                    }
+
                     if let Some(conversion) = Self::T::from_f64($coefficient) {
                         // Fractional conversion factors will end up being truncated.
                         if conversion.numer() >= conversion.denom() {
@@ -248,6 +274,8 @@ macro_rules! unit_units {
                 T::new(c.numer().to_biguint().unwrap(), c.denom().to_biguint().unwrap())
             }
 
+            unit_exact_fns!(ratio V);
+
             $(impl $crate::Conversion<V> for super::$unit {
                 type T = T;
 
@@ -255,13 +283,14 @@ macro_rules! unit_units {
                 #[allow(clippy::eq_op)]
                 #[allow(clippy::approx_constant)]
                 fn coefficient() -> Self::T {
-                    from_f64($coefficient)
+                    exact(unit_exact!($($exact)?)).unwrap_or_else(|| from_f64($coefficient))
                 }
 
                 #[inline(always)]
                 #[allow(unused_variables)]
                 fn constant(op: $crate::ConstantOp) -> Self::T {
-                    from_f64(unit_constant!(op $($constant)?))
+                    exact(unit_exact!($($($exact_constant)?)?))
+                        .unwrap_or_else(|| from_f64(unit_constant!(op $($constant)?)))
                 }
             }
 
@@ -273,6 +302,10 @@ macro_rules! unit_units {
                 fn is_valid() -> bool {
                     use $crate::num::{FromPrimitive, ToPrimitive};
 
+                    if let Some(conversion) = exact(unit_exact!($($exact)?)) {
+                        return conversion.numer() >= conversion.denom();
+                    }
+
                     if let Some(conversion) = $crate::num::rational::Ratio::<$crate::num::BigInt>::from_f64($coefficient) {
                         if conversion.numer() >= conversion.denom() {
                             if let Some(numer) = conversion.numer().to_f64() {
@@ -299,6 +332,8 @@ macro_rules! unit_units {
                 <V as $crate::num::FromPrimitive>::from_f64(value).unwrap()
             }
 
+            unit_exact_fns!(V);
+
             $(impl $crate::Conversion<V> for super::$unit {
                 type T = V;
 
@@ -306,13 +341,14 @@ macro_rules! unit_units {
                 #[allow(clippy::eq_op)]
                 #[allow(clippy::approx_constant)]
                 fn coefficient() -> Self::T {
-                    from_f64($coefficient)
+                    exact(unit_exact!($($exact)?)).unwrap_or_else(|| from_f64($coefficient))
                 }
 
                 #[inline(always)]
                 #[allow(unused_variables)]
                 fn constant(op: $crate::ConstantOp) -> Self::T {
-                    from_f64(unit_constant!(op $($constant)?))
+                    exact(unit_exact!($($($exact_constant)?)?))
+                        .unwrap_or_else(|| from_f64(unit_constant!(op $($constant)?)))
                 }
             }
 
@@ -324,6 +360,10 @@ macro_rules! unit_units {
                 fn is_valid() -> bool {
                     use $crate::num::{FromPrimitive, ToPrimitive};
 
+                    if let Some(conversion) = exact(unit_exact!($($exact)?)) {
+                        return conversion.numer() >= conversion.denom();
+                    }
+
                     if let Some(conversion) = Self::T::from_f64($coefficient) {
                         // Factional conversion factors will end up being truncated.
                         if conversion.numer() >= conversion.denom() {
@@ -411,3 +451,47 @@ macro_rules! unit_constant {
         }
     };
 }
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! unit_exact {
+    ($exact:expr) => {{
+        const EXACT: $crate::ExactFactor = $exact;
+
+        Some(EXACT)
+    }};
+    () => {
+        None
+    };
+}
+
+/// Defines a function, `exact`, to create the conversion factor type of a storage type module from
+/// an optional [`ExactFactor`](crate::ExactFactor). The `ratio` form builds a `Ratio<$V>`. Returns
+/// `None` when no exact factor is given or it can't be represented.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! unit_exact_fns {
+    (ratio $V:ty) => {
+        #[inline(always)]
+        fn exact(exact: Option<$crate::ExactFactor>) -> Option<$crate::num::rational::Ratio<$V>> {
+            use $crate::num::FromPrimitive;
+
+            let exact = exact?;
+
+            Some($crate::num::rational::Ratio::new(
+                <$V>::from_i128(exact.numer())?,
+                <$V>::from_i128(exact.denom())?,
+            ))
+        }
+    };
+    ($T:ty) => {
+        #[inline(always)]
+        fn exact(exact: Option<$crate::ExactFactor>) -> Option<$T> {
+            use $crate::num::FromPrimitive;
+
+            let exact = exact?;
+
+            Some(<$T>::from_i128(exact.numer())? / <$T>::from_i128(exact.denom())?)
+        }
+    };
+}
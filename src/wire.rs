@@ -0,0 +1,374 @@
+//! Compact binary encoding of quantities with their dimension, e.g. for telemetry links where
+//! JSON is too large. Available with the `wire` feature and usable with `no_std`.
+//!
+//! A quantity is encoded as a small header followed by the storage value in little-endian byte
+//! order:
+//!
+//! | Bytes         | Content                                                                 |
+//! |---------------|-------------------------------------------------------------------------|
+//! | 1             | Encoding version (high nibble) and storage type tag (low nibble).       |
+//! | 1             | Number of base quantities of the system, `n`.                           |
+//! | `(n + 1) / 2` | Dimension exponents as 4-bit two's complement values, low nibble first. |
+//! | 1             | Decimal scale, `s`, of the sender's base units as a signed byte.        |
+//! | 1 to 16       | Storage value.                                                          |
+//!
+//! The value multiplied by `10^s` is the value in the reference base units of the system, e.g.
+//! meters. A quantity stored in base units of kilometers has a scale of 3. When decoding, the
+//! dimension is verified against the target quantity, the value is converted to the target
+//! storage type, and rescaled to the target base units. The
+//! [kind](https://jcgm.bipm.org/vim/en/1.2.html) of a quantity isn't encoded.
+//!
+//! Quantities are encoded and decoded with the `encode_wire` and `decode_wire` methods generated
+//! by the `system!` macro.
+//!
+#![cfg_attr(all(feature = "si", feature = "f32", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f32", feature = "f64")), doc = " ```rust,ignore")]
+//! # use uom::si::length::meter;
+//! # use uom::si::time::second;
+//! use uom::si::{f32, f64};
+//!
+//! let v = f32::Length::new::<meter>(2.5) / f32::Time::new::<second>(1.0);
+//! let mut buffer = [0; f32::Velocity::WIRE_LEN];
+//! let len = v.encode_wire(&mut buffer).unwrap();
+//!
+//! assert_eq!(11, len);
+//! assert_eq!(2.5, f64::Velocity::decode_wire(&buffer).unwrap().0.value);
+//! assert!(f64::Length::decode_wire(&buffer).is_err());
+//! ```
+
+use crate::lib::fmt::{self, Display, Formatter};
+
+/// Version of the encoding.
+pub const VERSION: u8 = 1;
+
+/// Error encountered while encoding or decoding a quantity.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WireError {
+    /// The buffer is too small. Contains the number of bytes required.
+    BufferTooSmall(usize),
+
+    /// The encoding version isn't supported. Contains the version found.
+    Version(u8),
+
+    /// The storage type tag isn't recognized. Contains the tag found.
+    Storage(u8),
+
+    /// The number of base quantities doesn't match the system. Contains the number found.
+    BaseQuantities(usize),
+
+    /// A dimension exponent is outside of the encodable range, -8 through 7. Contains the
+    /// exponent.
+    Exponent(i32),
+
+    /// A base unit isn't a decimal multiple of the reference base unit of the system, e.g. foot,
+    /// or the combined scale is outside of the range of a signed byte.
+    Scale,
+
+    /// The value can't be represented by the target storage type, e.g. the value overflows or a
+    /// fractional value is decoded into an integer storage type.
+    Value,
+}
+
+impl Display for WireError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::BufferTooSmall(len) => {
+                write!(f, "buffer too small, {} bytes required", len)
+            }
+            WireError::Version(version) => write!(f, "unsupported encoding version {}", version),
+            WireError::Storage(tag) => write!(f, "unknown storage type tag {}", tag),
+            WireError::BaseQuantities(n) => {
+                write!(f, "unexpected number of base quantities {}", n)
+            }
+            WireError::Exponent(e) => write!(f, "dimension exponent {} out of range", e),
+            WireError::Scale => write!(f, "base units aren't a decimal multiple of the reference"),
+            WireError::Value => write!(f, "value out of range for storage type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::error::Error for WireError {}
+
+/// Storage value as read from an encoded quantity.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub enum Raw {
+    /// Signed integer.
+    Signed(i128),
+
+    /// Unsigned integer.
+    Unsigned(u128),
+
+    /// Single precision floating point number.
+    F32(f32),
+
+    /// Double precision floating point number.
+    F64(f64),
+}
+
+/// Underlying storage types that can be encoded: primitive integers and floating point types.
+/// `usize` and `isize` are encoded as 64-bit integers.
+pub trait WireValue: Sized {
+    /// Storage type tag.
+    const TAG: u8;
+
+    /// Size of the encoded value in bytes.
+    const SIZE: usize;
+
+    /// Writes the value to the beginning of `buffer`, which is at least [`SIZE`](Self::SIZE)
+    /// bytes long.
+    #[doc(hidden)]
+    fn write(&self, buffer: &mut [u8]);
+
+    /// Converts a value read from an encoded quantity. Returns `None` if the value can't be
+    /// represented, including fractional values for integer types.
+    #[doc(hidden)]
+    fn from_raw(raw: Raw) -> Option<Self>;
+
+    /// Multiplies the value by `10^shift`. Returns `None` if the result overflows or, for integer
+    /// types, isn't an integer.
+    #[doc(hidden)]
+    fn rescale(self, shift: i32) -> Option<Self>;
+}
+
+macro_rules! impl_wire_value {
+    ($($T:ident, $tag:expr, $raw:ident, $class:ident;)+) => {
+        $(impl WireValue for $T {
+            const TAG: u8 = $tag;
+            const SIZE: usize = crate::lib::mem::size_of::<$T>();
+
+            #[inline]
+            fn write(&self, buffer: &mut [u8]) {
+                buffer[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+            }
+
+            impl_wire_value!(@$class $T);
+        })+
+
+        /// Reads a value with the given storage type tag from the beginning of `buffer`. Returns
+        /// the value and its size in bytes.
+        fn read(tag: u8, buffer: &[u8]) -> Result<(Raw, usize), WireError> {
+            $(if tag == $tag {
+                const SIZE: usize = crate::lib::mem::size_of::<$T>();
+
+                let bytes = buffer.get(..SIZE).ok_or(WireError::BufferTooSmall(SIZE))?;
+                let mut value = [0; SIZE];
+
+                value.copy_from_slice(bytes);
+
+                #[allow(trivial_numeric_casts, clippy::cast_lossless)]
+                return Ok((Raw::$raw($T::from_le_bytes(value) as _), SIZE));
+            })+
+
+            Err(WireError::Storage(tag))
+        }
+    };
+    (@int $T:ident) => {
+        #[inline]
+        fn from_raw(raw: Raw) -> Option<Self> {
+            use crate::num::FromPrimitive;
+
+            // Reject fractional values rather than truncating them.
+            #[allow(trivial_numeric_casts, clippy::cast_precision_loss, clippy::float_cmp)]
+            match raw {
+                Raw::Signed(v) => Self::from_i128(v),
+                Raw::Unsigned(v) => Self::from_u128(v),
+                Raw::F32(v) => Self::from_f32(v).filter(|&i| i as f32 == v),
+                Raw::F64(v) => Self::from_f64(v).filter(|&i| i as f64 == v),
+            }
+        }
+
+        #[inline]
+        fn rescale(self, shift: i32) -> Option<Self> {
+            match <$T>::checked_pow(10, shift.unsigned_abs()) {
+                Some(factor) if shift >= 0 => self.checked_mul(factor),
+                Some(factor) if self % factor == 0 => Some(self / factor),
+                // The value is smaller than the factor so only zero divides evenly.
+                None if shift < 0 && self == 0 => Some(0),
+                _ => None,
+            }
+        }
+    };
+    (@float $T:ident) => {
+        #[inline]
+        fn from_raw(raw: Raw) -> Option<Self> {
+            use crate::num::FromPrimitive;
+
+            match raw {
+                Raw::Signed(v) => Self::from_i128(v),
+                Raw::Unsigned(v) => Self::from_u128(v),
+                Raw::F32(v) => Self::from_f32(v),
+                Raw::F64(v) => Self::from_f64(v),
+            }
+        }
+
+        #[inline]
+        fn rescale(self, shift: i32) -> Option<Self> {
+            let factor = <$T as crate::num::Float>::powi(10.0, shift.abs());
+            let value = if shift >= 0 { self * factor } else { self / factor };
+
+            // Overflow of a finite value to infinity can't be represented.
+            if value.is_infinite() && self.is_finite() {
+                None
+            }
+            else {
+                Some(value)
+            }
+        }
+    };
+}
+
+impl_wire_value! {
+    u8, 0, Unsigned, int;
+    u16, 1, Unsigned, int;
+    u32, 2, Unsigned, int;
+    u64, 3, Unsigned, int;
+    u128, 4, Unsigned, int;
+    i8, 5, Signed, int;
+    i16, 6, Signed, int;
+    i32, 7, Signed, int;
+    i64, 8, Signed, int;
+    i128, 9, Signed, int;
+    f32, 10, F32, float;
+    f64, 11, F64, float;
+}
+
+impl WireValue for usize {
+    const TAG: u8 = u64::TAG;
+    const SIZE: usize = u64::SIZE;
+
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        (*self as u64).write(buffer);
+    }
+
+    #[inline]
+    fn from_raw(raw: Raw) -> Option<Self> {
+        u64::from_raw(raw).and_then(|v| Self::try_from(v).ok())
+    }
+
+    #[inline]
+    fn rescale(self, shift: i32) -> Option<Self> {
+        (self as u64).rescale(shift).and_then(|v| Self::try_from(v).ok())
+    }
+}
+
+impl WireValue for isize {
+    const TAG: u8 = i64::TAG;
+    const SIZE: usize = i64::SIZE;
+
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        (*self as i64).write(buffer);
+    }
+
+    #[inline]
+    fn from_raw(raw: Raw) -> Option<Self> {
+        i64::from_raw(raw).and_then(|v| Self::try_from(v).ok())
+    }
+
+    #[inline]
+    fn rescale(self, shift: i32) -> Option<Self> {
+        (self as i64).rescale(shift).and_then(|v| Self::try_from(v).ok())
+    }
+}
+
+/// Length in bytes of the header of a quantity of a system with `n` base quantities.
+#[must_use]
+pub const fn header_len(n: usize) -> usize {
+    3 + (n + 1) / 2
+}
+
+/// Writes the header of a quantity to the beginning of `buffer` and returns its length.
+#[doc(hidden)]
+pub fn write_header(
+    buffer: &mut [u8],
+    tag: u8,
+    exponents: &[i32],
+    scale: i32,
+) -> Result<usize, WireError> {
+    let len = header_len(exponents.len());
+    let header = buffer.get_mut(..len).ok_or(WireError::BufferTooSmall(len))?;
+
+    header.iter_mut().for_each(|b| *b = 0);
+    header[0] = (VERSION << 4) | tag;
+    header[1] =
+        u8::try_from(exponents.len()).map_err(|_| WireError::BaseQuantities(exponents.len()))?;
+
+    for (i, &e) in exponents.iter().enumerate() {
+        if !(-8..=7).contains(&e) {
+            return Err(WireError::Exponent(e));
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let nibble = (e as u8) & 0x0F;
+
+        header[2 + i / 2] |= nibble << (4 * (i % 2));
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let scale = i8::try_from(scale).map_err(|_| WireError::Scale)? as u8;
+
+    header[len - 1] = scale;
+
+    Ok(len)
+}
+
+/// Reads the header and value of a quantity from the beginning of `buffer` into `exponents`.
+/// Returns the value, the decimal scale, and the total length in bytes.
+#[doc(hidden)]
+pub fn read_quantity(buffer: &[u8], exponents: &mut [i32]) -> Result<(Raw, i32, usize), WireError> {
+    let len = header_len(exponents.len());
+    let header = buffer.get(..2).ok_or(WireError::BufferTooSmall(len))?;
+
+    if header[0] >> 4 != VERSION {
+        return Err(WireError::Version(header[0] >> 4));
+    }
+
+    if usize::from(header[1]) != exponents.len() {
+        return Err(WireError::BaseQuantities(header[1].into()));
+    }
+
+    let header = buffer.get(..len).ok_or(WireError::BufferTooSmall(len))?;
+
+    for (i, e) in exponents.iter_mut().enumerate() {
+        // Shift the nibble to the high bits and back to sign-extend.
+        #[allow(clippy::cast_possible_wrap)]
+        let nibble = ((header[2 + i / 2] >> (4 * (i % 2))) << 4) as i8 >> 4;
+
+        *e = nibble.into();
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    let scale = header[len - 1] as i8;
+    let (value, size) = read(header[0] & 0x0F, &buffer[len..]).map_err(|e| match e {
+        WireError::BufferTooSmall(size) => WireError::BufferTooSmall(len + size),
+        e => e,
+    })?;
+
+    Ok((value, scale.into(), len + size))
+}
+
+/// Powers of ten matched by [`decimal_exponent`], from 10^-24 through 10^24. Literals are used
+/// rather than computed powers so that coefficients match exactly when possible.
+const POWERS_OF_TEN: [f64; 49] = [
+    1.0E-24, 1.0E-23, 1.0E-22, 1.0E-21, 1.0E-20, 1.0E-19, 1.0E-18, 1.0E-17, 1.0E-16, 1.0E-15,
+    1.0E-14, 1.0E-13, 1.0E-12, 1.0E-11, 1.0E-10, 1.0E-9, 1.0E-8, 1.0E-7, 1.0E-6, 1.0E-5, 1.0E-4,
+    1.0E-3, 1.0E-2, 1.0E-1, 1.0E0, 1.0E1, 1.0E2, 1.0E3, 1.0E4, 1.0E5, 1.0E6, 1.0E7, 1.0E8, 1.0E9,
+    1.0E10, 1.0E11, 1.0E12, 1.0E13, 1.0E14, 1.0E15, 1.0E16, 1.0E17, 1.0E18, 1.0E19, 1.0E20, 1.0E21,
+    1.0E22, 1.0E23, 1.0E24,
+];
+
+/// Returns the exponent `k` such that `coefficient` is `10^k`, allowing for the rounding error of
+/// single precision coefficients, or `None` if the coefficient isn't a power of ten.
+#[doc(hidden)]
+#[must_use]
+pub fn decimal_exponent(coefficient: f64) -> Option<i32> {
+    POWERS_OF_TEN
+        .iter()
+        .position(|&p| (1.0 - 1.0E-6..1.0 + 1.0E-6).contains(&(coefficient / p)))
+        .and_then(|i| i32::try_from(i).ok())
+        .map(|i| i - 24)
+}